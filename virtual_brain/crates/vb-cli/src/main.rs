@@ -45,6 +45,11 @@ enum Commands {
         /// Coupling strength
         #[arg(short, long, default_value = "1.0")]
         coupling: f64,
+
+        /// Path to a candle `safetensors` model driving perception/thought
+        /// inputs; falls back to the default analytic profile when absent.
+        #[arg(long)]
+        perception_model: Option<PathBuf>,
     },
 
     /// Display framework information
@@ -65,8 +70,9 @@ fn main() -> Result<()> {
             dt,
             n_oscillators,
             coupling,
+            perception_model,
         } => {
-            run_simulation(duration, dt, n_oscillators, coupling)?;
+            run_simulation(duration, dt, n_oscillators, coupling, perception_model.as_deref())?;
         }
         Commands::Info => {
             print_info();
@@ -103,9 +109,16 @@ fn run_validation(output_dir: &PathBuf, skip: &[String]) -> Result<()> {
     }
 }
 
-fn run_simulation(duration: f64, dt: f64, n_oscillators: usize, coupling: f64) -> Result<()> {
+fn run_simulation(
+    duration: f64,
+    dt: f64,
+    n_oscillators: usize,
+    coupling: f64,
+    perception_model: Option<&std::path::Path>,
+) -> Result<()> {
     use vb_core::types::MentalState;
     use vb_engine::PoincareComputer;
+    use vb_operators::ClosurePerceptionSource;
 
     println!("Running Virtual Brain Simulation");
     println!("================================");
@@ -113,12 +126,31 @@ fn run_simulation(duration: f64, dt: f64, n_oscillators: usize, coupling: f64) -
     println!("Time step: {} s", dt);
     println!("Oscillators: {}", n_oscillators);
     println!("Coupling: {}", coupling);
+    if let Some(path) = perception_model {
+        println!("Perception model: {}", path.display());
+    }
     println!();
 
     let mut computer = PoincareComputer::new(n_oscillators, coupling, 5);
     let initial = MentalState::default();
 
-    let states = computer.run_simulation(&initial, duration, dt);
+    let states = if let Some(model_path) = perception_model {
+        #[cfg(feature = "candle")]
+        {
+            use vb_operators::perception_source::candle_source::CandleModelSource;
+            let mut source = CandleModelSource::load(model_path)?;
+            computer.run_simulation_with_source(&initial, duration, dt, &mut source)
+        }
+        #[cfg(not(feature = "candle"))]
+        {
+            let _ = model_path;
+            println!("[WARN] built without the `candle` feature; falling back to the default perception profile");
+            let mut source = ClosurePerceptionSource::with_default_thought(|_t| 0.5);
+            computer.run_simulation_with_source(&initial, duration, dt, &mut source)
+        }
+    } else {
+        computer.run_simulation(&initial, duration, dt)
+    };
 
     // Print statistics
     let final_state = states.last().unwrap();
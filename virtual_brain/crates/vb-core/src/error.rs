@@ -51,6 +51,9 @@ pub enum TernaryAddrError {
 
     #[error("Ternary address must start with 'T', got {0}")]
     InvalidPrefix(String),
+
+    #[error("Packed byte count {0} does not match expected {1} for {2} trits")]
+    PackedLengthMismatch(usize, usize, usize),
 }
 
 /// Errors for MentalState operations
@@ -60,6 +63,23 @@ pub enum MentalStateError {
     OutOfBounds(String, f64),
 }
 
+/// Errors for anomaly-detection/alerting operations
+#[derive(Error, Debug)]
+pub enum DetectionError {
+    #[error("webhook send failed: {0}")]
+    WebhookSendFailed(String),
+}
+
+/// Errors for [`crate`]-level sampling-schedule configuration.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ScheduleError {
+    #[error("epoch [{start}, {end}) is inverted or empty")]
+    InvertedRange { start: f64, end: f64 },
+
+    #[error("malformed schedule config: {0}")]
+    Malformed(String),
+}
+
 /// General Virtual Brain errors
 #[derive(Error, Debug)]
 pub enum VBError {
@@ -75,6 +95,12 @@ pub enum VBError {
     #[error("Mental state error: {0}")]
     MentalState(#[from] MentalStateError),
 
+    #[error("Detection error: {0}")]
+    Detection(#[from] DetectionError),
+
+    #[error("Sampling schedule error: {0}")]
+    Schedule(#[from] ScheduleError),
+
     #[error("Computation error: {0}")]
     Computation(String),
 
@@ -5,6 +5,7 @@
 
 use crate::error::MentalStateError;
 use crate::types::{PartitionCoord, SCoord};
+use num_traits::{Float, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 /// Complete mental state representation.
@@ -13,34 +14,46 @@ use serde::{Deserialize, Serialize};
 /// - gamma: Phase coherence (Kuramoto order parameter R)
 /// - gamma_f: Frequency coherence (global frequency locking)
 /// - m: Memory integral (accumulated entropy changes)
+///
+/// Generic over the floating-point scalar `Scalar` used for its numeric
+/// fields, defaulting to `f64`. Running in `f32` (see [`MentalStateF32`])
+/// halves the memory/bandwidth of large oscillator-population simulations
+/// and is a stepping stone toward SIMD/GPU backends; `f64` remains the
+/// framework default so existing call sites are unaffected.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MentalState {
+pub struct MentalState<Scalar: Float = f64> {
     /// Phase coherence (Kuramoto order parameter R) in [0, 1]
-    pub gamma: f64,
+    pub gamma: Scalar,
     /// Frequency coherence (global frequency locking) in [0, 1]
-    pub gamma_f: f64,
+    pub gamma_f: Scalar,
     /// Memory integral (accumulated entropy changes)
-    pub m: f64,
+    pub m: Scalar,
 
     /// Current S-entropy coordinate
     pub s_coord: Option<SCoord>,
     /// Current partition coordinate
     pub partition: Option<PartitionCoord>,
     /// Time of this state
-    pub timestamp: f64,
+    pub timestamp: Scalar,
 
     /// Perception decay level in [0, 1]
-    pub p_decay: f64,
+    pub p_decay: Scalar,
     /// Thought decay level in [0, 1]
-    pub t_decay: f64,
+    pub t_decay: Scalar,
 
     /// Trajectory history
     pub trajectory: Vec<SCoord>,
 }
 
-impl MentalState {
+/// [`MentalState`] specialized to `f64` (the framework default).
+pub type MentalStateF64 = MentalState<f64>;
+/// [`MentalState`] specialized to `f32`, for lower-precision/higher-throughput
+/// simulations.
+pub type MentalStateF32 = MentalState<f32>;
+
+impl<Scalar: Float> MentalState<Scalar> {
     /// Create new MentalState with validation.
-    pub fn new(gamma: f64, gamma_f: f64, m: f64) -> Result<Self, MentalStateError> {
+    pub fn new(gamma: Scalar, gamma_f: Scalar, m: Scalar) -> Result<Self, MentalStateError> {
         Self::validate_range("gamma", gamma)?;
         Self::validate_range("gamma_f", gamma_f)?;
 
@@ -50,110 +63,114 @@ impl MentalState {
             m,
             s_coord: None,
             partition: None,
-            timestamp: 0.0,
-            p_decay: 1.0,
-            t_decay: 1.0,
+            timestamp: Scalar::zero(),
+            p_decay: Scalar::one(),
+            t_decay: Scalar::one(),
             trajectory: Vec::new(),
         })
     }
 
-    fn validate_range(name: &str, val: f64) -> Result<(), MentalStateError> {
-        if !(0.0..=1.0).contains(&val) {
-            return Err(MentalStateError::OutOfBounds(name.to_string(), val));
+    fn validate_range(name: &str, val: Scalar) -> Result<(), MentalStateError> {
+        if val < Scalar::zero() || val > Scalar::one() {
+            return Err(MentalStateError::OutOfBounds(
+                name.to_string(),
+                val.to_f64().unwrap_or(f64::NAN),
+            ));
         }
         Ok(())
     }
 
     /// Consciousness level: C = P_decay * T_decay * gamma * gamma_f
-    pub fn consciousness(&self) -> f64 {
+    pub fn consciousness(&self) -> Scalar {
         self.p_decay * self.t_decay * self.gamma * self.gamma_f
     }
 
     /// Check if consciousness threshold is met.
     pub fn is_conscious(&self) -> bool {
-        self.consciousness() > 0.5
+        self.consciousness() > Scalar::from(0.5).unwrap()
     }
 
     /// Check if in dream state.
     pub fn is_dreaming(&self) -> bool {
-        self.p_decay < 0.1 && self.t_decay > 0.5 && self.gamma_f > 0.5
+        let half = Scalar::from(0.5).unwrap();
+        self.p_decay < Scalar::from(0.1).unwrap() && self.t_decay > half && self.gamma_f > half
     }
 
     /// Check if in awake state.
     pub fn is_awake(&self) -> bool {
-        self.p_decay > 0.7 && self.gamma > 0.5
+        self.p_decay > Scalar::from(0.7).unwrap() && self.gamma > Scalar::from(0.5).unwrap()
     }
 
     /// Enter dream state (P_decay = 0).
     pub fn enter_dream(&self) -> Self {
         let mut new_state = self.clone();
-        new_state.p_decay = 0.0;
+        new_state.p_decay = Scalar::zero();
         new_state
     }
 
     /// Wake from dream state.
-    pub fn wake(&self, perception_level: f64) -> Self {
+    pub fn wake(&self, perception_level: Scalar) -> Self {
         let mut new_state = self.clone();
-        new_state.p_decay = perception_level.clamp(0.0, 1.0);
+        new_state.p_decay = clamp_unit(perception_level);
         new_state
     }
 
     /// Create initial mental state.
     pub fn initial(s_coord: Option<SCoord>, partition: Option<PartitionCoord>) -> Self {
         Self {
-            gamma: 0.5,
-            gamma_f: 0.5,
-            m: 0.0,
+            gamma: Scalar::from(0.5).unwrap(),
+            gamma_f: Scalar::from(0.5).unwrap(),
+            m: Scalar::zero(),
             s_coord,
             partition,
-            timestamp: 0.0,
-            p_decay: 1.0,
-            t_decay: 1.0,
+            timestamp: Scalar::zero(),
+            p_decay: Scalar::one(),
+            t_decay: Scalar::one(),
             trajectory: Vec::new(),
         }
     }
 
     /// Update with new gamma value.
-    pub fn with_gamma(&self, gamma: f64) -> Self {
+    pub fn with_gamma(&self, gamma: Scalar) -> Self {
         let mut new_state = self.clone();
-        new_state.gamma = gamma.clamp(0.0, 1.0);
+        new_state.gamma = clamp_unit(gamma);
         new_state
     }
 
     /// Update with new gamma_f value.
-    pub fn with_gamma_f(&self, gamma_f: f64) -> Self {
+    pub fn with_gamma_f(&self, gamma_f: Scalar) -> Self {
         let mut new_state = self.clone();
-        new_state.gamma_f = gamma_f.clamp(0.0, 1.0);
+        new_state.gamma_f = clamp_unit(gamma_f);
         new_state
     }
 
     /// Update memory.
-    pub fn with_memory(&self, m: f64) -> Self {
+    pub fn with_memory(&self, m: Scalar) -> Self {
         let mut new_state = self.clone();
         new_state.m = m;
         new_state
     }
 
     /// Apply perception decay.
-    pub fn decay_perception(&self, tau_p: f64, dt: f64) -> Self {
+    pub fn decay_perception(&self, tau_p: Scalar, dt: Scalar) -> Self {
         let mut new_state = self.clone();
-        new_state.p_decay *= (-dt / tau_p).exp();
-        new_state.timestamp += dt;
+        new_state.p_decay = new_state.p_decay * (-dt / tau_p).exp();
+        new_state.timestamp = new_state.timestamp + dt;
         new_state
     }
 
     /// Apply thought decay.
-    pub fn decay_thought(&self, tau_t: f64, dt: f64) -> Self {
+    pub fn decay_thought(&self, tau_t: Scalar, dt: Scalar) -> Self {
         let mut new_state = self.clone();
-        new_state.t_decay *= (-dt / tau_t).exp();
-        new_state.timestamp += dt;
+        new_state.t_decay = new_state.t_decay * (-dt / tau_t).exp();
+        new_state.timestamp = new_state.timestamp + dt;
         new_state
     }
 
     /// Update memory integral: M += (dH/dt) * dt
-    pub fn update_memory(&self, dh_dt: f64, dt: f64) -> Self {
+    pub fn update_memory(&self, dh_dt: Scalar, dt: Scalar) -> Self {
         let mut new_state = self.clone();
-        new_state.m += dh_dt.abs() * dt;
+        new_state.m = new_state.m + dh_dt.abs() * dt;
         new_state
     }
 
@@ -173,7 +190,13 @@ impl MentalState {
     }
 }
 
-impl Default for MentalState {
+/// Clamp a scalar into [0, 1]; `num_traits::Float` has no inherent `clamp`,
+/// unlike the primitive `f32`/`f64` methods, so this mirrors it via `max`/`min`.
+fn clamp_unit<Scalar: Float>(val: Scalar) -> Scalar {
+    val.max(Scalar::zero()).min(Scalar::one())
+}
+
+impl<Scalar: Float> Default for MentalState<Scalar> {
     fn default() -> Self {
         Self::initial(None, None)
     }
@@ -218,4 +241,37 @@ mod tests {
         assert!(MentalState::new(0.5, -0.1, 0.0).is_err());
         assert!(MentalState::new(0.5, 0.5, 0.0).is_ok());
     }
+
+    /// Exercises the consciousness formula and perception decay in both
+    /// `f32` and `f64`, checking each agrees with the closed-form
+    /// expectation within that type's own epsilon.
+    fn consciousness_and_decay_within_epsilon<Scalar: Float + std::fmt::Debug>() {
+        let gamma = Scalar::from(0.8).unwrap();
+        let gamma_f = Scalar::from(0.9).unwrap();
+        let state: MentalState<Scalar> = MentalState {
+            gamma,
+            gamma_f,
+            ..MentalState::initial(None, None)
+        };
+
+        let expected = gamma * gamma_f;
+        let eps = Scalar::epsilon() * Scalar::from(10.0).unwrap();
+        assert!((state.consciousness() - expected).abs() < eps);
+
+        let tau = Scalar::from(0.05).unwrap();
+        let dt = Scalar::from(0.01).unwrap();
+        let decayed = state.decay_perception(tau, dt);
+        assert!(decayed.p_decay < Scalar::one());
+        assert!(decayed.timestamp > Scalar::zero());
+    }
+
+    #[test]
+    fn test_consciousness_and_decay_f64() {
+        consciousness_and_decay_within_epsilon::<f64>();
+    }
+
+    #[test]
+    fn test_consciousness_and_decay_f32() {
+        consciousness_and_decay_within_epsilon::<f32>();
+    }
 }
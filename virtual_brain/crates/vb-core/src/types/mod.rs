@@ -15,7 +15,7 @@ pub mod ternary_addr;
 
 // Re-exports
 pub use circuit_state::{CircuitRegime, CircuitState};
-pub use mental_state::MentalState;
+pub use mental_state::{MentalState, MentalStateF32, MentalStateF64};
 pub use partition_coord::{PartitionCoord, Spin};
 pub use s_coord::SCoord;
 pub use ternary_addr::TernaryAddr;
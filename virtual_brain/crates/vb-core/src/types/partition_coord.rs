@@ -17,6 +17,7 @@
 
 use crate::error::PartitionCoordError;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 
 /// Spin values representing chirality
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -222,6 +223,106 @@ impl PartitionCoord {
     pub fn energy_level(&self, e_max: f64, n_max: i32) -> f64 {
         e_max * (self.n as f64 / n_max as f64).powi(2)
     }
+
+    /// Whether a direct transition `self -> other` obeys electric-dipole
+    /// style selection rules: `Δl = ±1`, `Δm ∈ {-1, 0, +1}`, `n` may
+    /// change by any nonzero amount (`Δn != 0`), and spin is conserved
+    /// unless `allow_spin_flip` is set.
+    pub fn is_allowed_transition(&self, other: &PartitionCoord, allow_spin_flip: bool) -> bool {
+        let dl = other.l - self.l;
+        let dm = other.m - self.m;
+        let spin_ok = allow_spin_flip || self.s == other.s;
+        dl.abs() == 1 && dm.abs() <= 1 && other.n != self.n && spin_ok
+    }
+
+    /// Enumerate the categorical states directly reachable from `self`
+    /// under [`PartitionCoord::is_allowed_transition`] with spin
+    /// conserved. `n` is bounded to the adjacent levels `n-1`/`n+1` to
+    /// keep the neighbor set finite; [`PartitionCoord::transition_path`]
+    /// explores the full multi-level space by chaining these single-step
+    /// neighborhoods.
+    pub fn allowed_transitions(&self) -> Vec<PartitionCoord> {
+        let mut out = Vec::new();
+        for n in [self.n - 1, self.n + 1] {
+            if n < 1 {
+                continue;
+            }
+            for dl in [-1, 1] {
+                let l = self.l + dl;
+                if l < 0 || l >= n {
+                    continue;
+                }
+                for dm in [-1, 0, 1] {
+                    let m = self.m + dm;
+                    if m < -l || m > l {
+                        continue;
+                    }
+                    if let Ok(candidate) = PartitionCoord::new(n, l, m, self.s) {
+                        out.push(candidate);
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Shortest sequence of allowed transitions from `self` to `target`,
+    /// found by BFS over categorical states up to level `n_max`. Uses
+    /// `to_linear_index()` as an O(1) visited-set key and
+    /// `total_capacity(n_max)` to size that visited set. Returns `None` if
+    /// selection rules can't reach `target` within that bound.
+    pub fn transition_path(
+        &self,
+        target: &PartitionCoord,
+        n_max: i32,
+    ) -> Option<Vec<PartitionCoord>> {
+        if self == target {
+            return Some(vec![*self]);
+        }
+
+        let capacity = Self::total_capacity(n_max).max(1) as usize;
+        let mut visited = vec![false; capacity];
+        let mut parent: HashMap<i64, i64> = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        let start_idx = self.to_linear_index();
+        if (start_idx as usize) < visited.len() {
+            visited[start_idx as usize] = true;
+        }
+        queue.push_back(*self);
+
+        while let Some(current) = queue.pop_front() {
+            for next in current.allowed_transitions() {
+                if next.n > n_max {
+                    continue;
+                }
+                let idx = next.to_linear_index();
+                if idx < 0 || idx as usize >= visited.len() || visited[idx as usize] {
+                    continue;
+                }
+                visited[idx as usize] = true;
+                parent.insert(idx, current.to_linear_index());
+
+                if next == *target {
+                    let mut path_indices = vec![idx];
+                    let mut cur = idx;
+                    while cur != start_idx {
+                        cur = parent[&cur];
+                        path_indices.push(cur);
+                    }
+                    path_indices.reverse();
+                    return path_indices
+                        .into_iter()
+                        .map(|i| PartitionCoord::from_linear_index(i).ok())
+                        .collect();
+                }
+
+                queue.push_back(next);
+            }
+        }
+
+        None
+    }
 }
 
 impl Default for PartitionCoord {
@@ -271,4 +372,62 @@ mod tests {
         assert!(PartitionCoord::new(1, 1, 0, Spin::Up).is_err()); // l >= n
         assert!(PartitionCoord::new(2, 1, 2, Spin::Up).is_err()); // m > l
     }
+
+    #[test]
+    fn test_is_allowed_transition_requires_dl_one_and_n_change() {
+        let a = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let b = PartitionCoord::new(3, 2, 0, Spin::Up).unwrap(); // dl = +1, dn != 0
+        let same_n = PartitionCoord::new(2, 0, 0, Spin::Up).unwrap(); // dn == 0
+        let dl_two = PartitionCoord::new(4, 3, 0, Spin::Up).unwrap(); // dl = +2
+
+        assert!(a.is_allowed_transition(&b, false));
+        assert!(!a.is_allowed_transition(&same_n, false));
+        assert!(!a.is_allowed_transition(&dl_two, false));
+    }
+
+    #[test]
+    fn test_is_allowed_transition_respects_spin_conservation() {
+        let a = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let flipped = PartitionCoord::new(3, 2, 0, Spin::Down).unwrap();
+
+        assert!(!a.is_allowed_transition(&flipped, false));
+        assert!(a.is_allowed_transition(&flipped, true));
+    }
+
+    #[test]
+    fn test_allowed_transitions_all_satisfy_selection_rules() {
+        let origin = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let neighbors = origin.allowed_transitions();
+        assert!(!neighbors.is_empty());
+        for n in &neighbors {
+            assert!(origin.is_allowed_transition(n, false));
+        }
+    }
+
+    #[test]
+    fn test_transition_path_trivial_when_already_at_target() {
+        let c = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let path = c.transition_path(&c, 4).unwrap();
+        assert_eq!(path, vec![c]);
+    }
+
+    #[test]
+    fn test_transition_path_finds_shortest_allowed_route() {
+        let start = PartitionCoord::new(1, 0, 0, Spin::Up).unwrap();
+        let target = PartitionCoord::new(2, 1, 1, Spin::Up).unwrap();
+
+        let path = start.transition_path(&target, 4).expect("path should exist");
+        assert_eq!(path.first().unwrap(), &start);
+        assert_eq!(path.last().unwrap(), &target);
+        for pair in path.windows(2) {
+            assert!(pair[0].is_allowed_transition(&pair[1], false));
+        }
+    }
+
+    #[test]
+    fn test_transition_path_none_when_bound_too_small() {
+        let start = PartitionCoord::new(1, 0, 0, Spin::Up).unwrap();
+        let target = PartitionCoord::new(5, 4, 4, Spin::Up).unwrap();
+        assert!(start.transition_path(&target, 1).is_none());
+    }
 }
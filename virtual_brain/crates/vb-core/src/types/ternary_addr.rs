@@ -1,13 +1,20 @@
 //! TernaryAddr: Ternary addressing with O(log3 n) navigation.
 //!
 //! The ternary system provides 37% efficiency improvement over binary
-//! (log_2(3) ≈ 1.585 bits per trit vs 1 bit per bit).
+//! (log_2(3) ≈ 1.585 bits per trit vs 1 bit per bit). `digits: Vec<u8>`
+//! only spends that efficiency at the arithmetic level though — one trit
+//! per `u8` wastes ~6 bits per digit on the wire. [`TernaryAddr::pack`]
+//! recovers it for serialization via base-243 run packing (as in rustc's
+//! `base_n`): 3^5 = 243 < 256, so five trits pack into a single byte.
 
 use crate::error::TernaryAddrError;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Number of trits packed into each byte: the largest `k` with `3^k < 256`.
+const TRITS_PER_BYTE: usize = 5;
 
 /// Ternary address for O(log3 n) categorical navigation.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TernaryAddr {
     digits: Vec<u8>,
 }
@@ -203,6 +210,95 @@ impl TernaryAddr {
         path
     }
 
+    /// Enumerate every address in the subtree rooted at `self`, from
+    /// `self` itself down to `max_depth` levels below it (breadth-first
+    /// by relative depth). Lets `TernaryAddr` be used as a spatial index
+    /// over a region rather than only a single point.
+    pub fn descendants(&self, max_depth: usize) -> impl Iterator<Item = TernaryAddr> {
+        let mut all = vec![self.clone()];
+        let mut frontier = vec![self.clone()];
+        for _ in 0..max_depth {
+            let mut next = Vec::with_capacity(frontier.len() * 3);
+            for addr in &frontier {
+                for direction in 0..3u8 {
+                    next.push(addr.navigate(direction).unwrap());
+                }
+            }
+            all.extend(next.iter().cloned());
+            frontier = next;
+        }
+        all.into_iter()
+    }
+
+    /// True when `self` is a prefix of `other`, i.e. `other` addresses a
+    /// point somewhere in the subtree rooted at `self`.
+    pub fn contains(&self, other: &TernaryAddr) -> bool {
+        other.digits.len() >= self.digits.len() && other.digits[..self.digits.len()] == self.digits[..]
+    }
+
+    /// The minimal set of depth-`depth` addresses whose `interval()`s tile
+    /// `[value_low, value_high]`: every depth-`depth` address is a
+    /// half-open bin of width `3^-depth`, so this just maps the endpoints
+    /// to bin indices and returns every address in that (inclusive) range.
+    pub fn covering(value_low: f64, value_high: f64, depth: usize) -> Vec<TernaryAddr> {
+        let n = 3u64.pow(depth as u32);
+        let index_of = |v: f64| -> u64 {
+            let v = v.clamp(0.0, 1.0);
+            ((v * n as f64).floor() as u64).min(n - 1)
+        };
+
+        let lo = index_of(value_low);
+        let hi = index_of(value_high);
+        (lo..=hi)
+            .filter_map(|i| TernaryAddr::encode(i, depth).ok())
+            .collect()
+    }
+
+    /// Pack digits into base-243 runs of 5 trits per byte:
+    /// `b = Σ d_i · 3^i` for each run, `i` in `[0, run.len())`. The final
+    /// run may be short; the trit count (`self.depth()`) disambiguates it
+    /// on unpack. An empty (root) address packs to zero bytes.
+    pub fn pack(&self) -> Vec<u8> {
+        self.digits
+            .chunks(TRITS_PER_BYTE)
+            .map(|run| {
+                run.iter()
+                    .enumerate()
+                    .fold(0u32, |acc, (i, &d)| acc + d as u32 * 3u32.pow(i as u32)) as u8
+            })
+            .collect()
+    }
+
+    /// Inverse of [`Self::pack`]: reconstruct a `TernaryAddr` with `len`
+    /// trits from its packed bytes. Rejects a byte count that doesn't match
+    /// `len` (`ceil(len / 5)` bytes expected) and any byte `>= 243` (not a
+    /// valid base-243 run of up to 5 trits).
+    pub fn unpack(bytes: &[u8], len: usize) -> Result<Self, TernaryAddrError> {
+        let expected_bytes = len.div_ceil(TRITS_PER_BYTE);
+        if bytes.len() != expected_bytes {
+            return Err(TernaryAddrError::PackedLengthMismatch(
+                bytes.len(),
+                expected_bytes,
+                len,
+            ));
+        }
+
+        let mut digits = Vec::with_capacity(len);
+        for (chunk_idx, &b) in bytes.iter().enumerate() {
+            if b >= 243 {
+                return Err(TernaryAddrError::InvalidDigit(b));
+            }
+            let run_len = (len - chunk_idx * TRITS_PER_BYTE).min(TRITS_PER_BYTE);
+            let mut val = b as u32;
+            for _ in 0..run_len {
+                digits.push((val % 3) as u8);
+                val /= 3;
+            }
+        }
+
+        Ok(Self { digits })
+    }
+
     /// Root address (empty).
     pub fn root() -> Self {
         Self { digits: vec![] }
@@ -242,6 +338,37 @@ impl Default for TernaryAddr {
     }
 }
 
+/// Wire format used by the custom `Serialize`/`Deserialize` below: the
+/// packed bytes plus the trit count needed to unpack them unambiguously.
+#[derive(Serialize, Deserialize)]
+struct PackedTernaryAddr {
+    packed: Vec<u8>,
+    len: usize,
+}
+
+impl Serialize for TernaryAddr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PackedTernaryAddr {
+            packed: self.pack(),
+            len: self.digits.len(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TernaryAddr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = PackedTernaryAddr::deserialize(deserializer)?;
+        TernaryAddr::unpack(&repr.packed, repr.len).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,4 +415,90 @@ mod tests {
         let parsed = TernaryAddr::from_string(&s).unwrap();
         assert_eq!(addr, parsed);
     }
+
+    #[test]
+    fn test_root_packs_to_zero_bytes() {
+        let root = TernaryAddr::root();
+        assert!(root.pack().is_empty());
+        assert_eq!(TernaryAddr::unpack(&[], 0).unwrap(), root);
+    }
+
+    #[test]
+    fn test_pack_unpack_roundtrip_across_depths() {
+        for depth in 1..=17 {
+            let digits: Vec<u8> = (0..depth).map(|i| (i % 3) as u8).collect();
+            let addr = TernaryAddr::new(digits).unwrap();
+            let packed = addr.pack();
+            assert_eq!(packed.len(), (depth as usize).div_ceil(TRITS_PER_BYTE));
+            let unpacked = TernaryAddr::unpack(&packed, addr.depth()).unwrap();
+            assert_eq!(addr, unpacked);
+        }
+    }
+
+    #[test]
+    fn test_unpack_rejects_length_mismatch() {
+        let addr = TernaryAddr::new(vec![0, 1, 2, 0, 1, 2]).unwrap();
+        let packed = addr.pack();
+        // 6 trits pack into 2 bytes; 11 trits would need 3.
+        let err = TernaryAddr::unpack(&packed, addr.depth() + TRITS_PER_BYTE).unwrap_err();
+        assert!(matches!(err, TernaryAddrError::PackedLengthMismatch(_, _, _)));
+    }
+
+    #[test]
+    fn test_unpack_rejects_byte_out_of_range() {
+        let err = TernaryAddr::unpack(&[243], 5).unwrap_err();
+        assert!(matches!(err, TernaryAddrError::InvalidDigit(243)));
+    }
+
+    #[test]
+    fn test_descendants_counts_full_subtree() {
+        let root = TernaryAddr::root();
+        let descendants: Vec<_> = root.descendants(2).collect();
+        // self + 3 (depth 1) + 9 (depth 2) = 13
+        assert_eq!(descendants.len(), 13);
+        assert!(descendants.iter().any(|a| a.depth() == 0));
+        assert!(descendants.iter().filter(|a| a.depth() == 1).count() == 3);
+        assert!(descendants.iter().filter(|a| a.depth() == 2).count() == 9);
+    }
+
+    #[test]
+    fn test_contains_checks_prefix_relationship() {
+        let parent = TernaryAddr::new(vec![0, 1]).unwrap();
+        let child = TernaryAddr::new(vec![0, 1, 2]).unwrap();
+        let unrelated = TernaryAddr::new(vec![1, 1, 2]).unwrap();
+        assert!(parent.contains(&child));
+        assert!(parent.contains(&parent));
+        assert!(!parent.contains(&unrelated));
+        assert!(!child.contains(&parent));
+    }
+
+    #[test]
+    fn test_covering_tiles_full_range() {
+        let addrs = TernaryAddr::covering(0.0, 1.0, 2);
+        assert_eq!(addrs.len(), 9);
+        for (i, addr) in addrs.iter().enumerate() {
+            assert_eq!(addr.decode(), i as u64);
+        }
+    }
+
+    #[test]
+    fn test_covering_tiles_partial_range() {
+        let addrs = TernaryAddr::covering(0.2, 0.5, 2);
+        for addr in &addrs {
+            let (low, high) = addr.interval();
+            assert!(low < 0.5 && high > 0.2);
+        }
+        // Every bin strictly between the endpoints must be included.
+        let decoded: Vec<u64> = addrs.iter().map(|a| a.decode()).collect();
+        assert_eq!(decoded, (decoded[0]..=*decoded.last().unwrap()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_serde_roundtrip_uses_packed_form() {
+        let addr = TernaryAddr::new(vec![2, 1, 0, 2, 1, 0, 2]).unwrap();
+        let json = serde_json::to_string(&addr).unwrap();
+        let back: TernaryAddr = serde_json::from_str(&json).unwrap();
+        assert_eq!(addr, back);
+        assert!(json.contains("\"len\":7"));
+    }
 }
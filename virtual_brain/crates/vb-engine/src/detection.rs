@@ -0,0 +1,363 @@
+//! Detection: Real-time anomaly detection over biological oscillation
+//! streams, with pluggable analytic units and a webhook-driven runner.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::net::TcpStream;
+use vb_core::error::DetectionError;
+use vb_core::types::SCoord;
+
+/// A single pluggable anomaly-detection analytic, serializable so
+/// configurations persist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DetectionUnit {
+    /// Flags when the most recent sample in the window (e.g. a
+    /// `coherence_measure` or band power) leaves `[lower, upper]`.
+    Threshold { lower: f64, upper: f64 },
+    /// Flags when the tail of the window correlates poorly with a
+    /// learned healthy `template`.
+    Pattern {
+        template: Vec<f64>,
+        min_correlation: f64,
+    },
+    /// Rolling z-score over the last `base_period` samples: a
+    /// lightweight alternative to a full Holt-Winters seasonal
+    /// decomposition, flagging deviation from the expected rhythm.
+    Seasonal { base_period: usize, z_threshold: f64 },
+}
+
+impl DetectionUnit {
+    /// Evaluate the most recent `window` of samples. Returns a severity
+    /// score (higher = more anomalous) if this unit fires, or `None` if
+    /// the window looks healthy.
+    pub fn evaluate(&self, window: &[f64]) -> Option<f64> {
+        match self {
+            DetectionUnit::Threshold { lower, upper } => {
+                let latest = *window.last()?;
+                if latest < *lower {
+                    Some(lower - latest)
+                } else if latest > *upper {
+                    Some(latest - upper)
+                } else {
+                    None
+                }
+            }
+            DetectionUnit::Pattern {
+                template,
+                min_correlation,
+            } => {
+                let n = template.len().min(window.len());
+                if n < 2 {
+                    return None;
+                }
+                let tail = &window[window.len() - n..];
+                let tmpl = &template[template.len() - n..];
+                let correlation = pearson_correlation(tail, tmpl);
+                if correlation < *min_correlation {
+                    Some(min_correlation - correlation)
+                } else {
+                    None
+                }
+            }
+            DetectionUnit::Seasonal {
+                base_period,
+                z_threshold,
+            } => {
+                if *base_period == 0 || window.len() < base_period + 1 {
+                    return None;
+                }
+                let history = &window[window.len() - base_period - 1..window.len() - 1];
+                let mean = history.iter().sum::<f64>() / history.len() as f64;
+                let variance = history.iter().map(|v| (v - mean).powi(2)).sum::<f64>()
+                    / history.len() as f64;
+                let std_dev = variance.sqrt();
+                if std_dev <= 0.0 {
+                    return None;
+                }
+                let latest = *window.last()?;
+                let z = (latest - mean) / std_dev;
+                if z.abs() > *z_threshold {
+                    Some(z.abs() - z_threshold)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn pearson_correlation(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len() as f64;
+    let mean_a = a.iter().sum::<f64>() / n;
+    let mean_b = b.iter().sum::<f64>() / n;
+
+    let mut covariance = 0.0;
+    let mut var_a = 0.0;
+    let mut var_b = 0.0;
+    for (&x, &y) in a.iter().zip(b.iter()) {
+        let da = x - mean_a;
+        let db = y - mean_b;
+        covariance += da * db;
+        var_a += da * da;
+        var_b += db * db;
+    }
+
+    if var_a <= 0.0 || var_b <= 0.0 {
+        return 0.0;
+    }
+    covariance / (var_a.sqrt() * var_b.sqrt())
+}
+
+/// How severe a fired [`Alert`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlertSeverity {
+    Info,
+    Warning,
+    Critical,
+}
+
+/// A single fired detection, ready to be POSTed to a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub oscillation_type: String,
+    pub offending_scale: String,
+    pub timestamp: String,
+    pub severity: AlertSeverity,
+    pub coordinates: SCoord,
+}
+
+/// Destination for fired [`Alert`]s.
+pub trait WebhookSink {
+    fn send(&self, alert: &Alert) -> Result<(), DetectionError>;
+}
+
+/// POSTs the alert as a JSON body to a plain-HTTP webhook URL
+/// (`http://host[:port]/path`) using a raw `TcpStream`, since this
+/// workspace has no HTTP client dependency.
+#[derive(Debug, Clone)]
+pub struct HttpWebhookSink {
+    pub url: String,
+}
+
+impl WebhookSink for HttpWebhookSink {
+    fn send(&self, alert: &Alert) -> Result<(), DetectionError> {
+        let body = serde_json::to_string(alert)
+            .map_err(|e| DetectionError::WebhookSendFailed(e.to_string()))?;
+        post_json(&self.url, &body)
+    }
+}
+
+/// POSTs a pre-serialized JSON `body` to a plain-HTTP `url` over a raw
+/// `TcpStream`, shared by every webhook-dispatching subsystem in this
+/// crate since no HTTP client dependency is available here.
+pub(crate) fn post_json(url: &str, body: &str) -> Result<(), DetectionError> {
+    let (host, port, path) = parse_http_url(url)
+        .ok_or_else(|| DetectionError::WebhookSendFailed(format!("invalid webhook url: {url}")))?;
+
+    let mut stream =
+        TcpStream::connect((host.as_str(), port)).map_err(|e| DetectionError::WebhookSendFailed(e.to_string()))?;
+
+    let request = format!(
+        "POST {path} HTTP/1.1\r\n\
+         Host: {host}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n\
+         {body}",
+        body.len()
+    );
+
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| DetectionError::WebhookSendFailed(e.to_string()))?;
+    Ok(())
+}
+
+fn parse_http_url(url: &str) -> Option<(String, u16, String)> {
+    let rest = url.strip_prefix("http://")?;
+    let (authority, path) = match rest.find('/') {
+        Some(idx) => (&rest[..idx], &rest[idx..]),
+        None => (rest, "/"),
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((h, p)) => (h.to_string(), p.parse().ok()?),
+        None => (authority.to_string(), 80_u16),
+    };
+    let path = if path.is_empty() {
+        "/".to_string()
+    } else {
+        path.to_string()
+    };
+    Some((host, port, path))
+}
+
+/// A [`WebhookSink`] that records alerts in memory instead of sending
+/// them, for tests and dry runs.
+#[derive(Debug, Default)]
+pub struct RecordingWebhookSink {
+    pub sent: std::sync::Mutex<Vec<Alert>>,
+}
+
+impl WebhookSink for RecordingWebhookSink {
+    fn send(&self, alert: &Alert) -> Result<(), DetectionError> {
+        self.sent.lock().unwrap().push(alert.clone());
+        Ok(())
+    }
+}
+
+/// Drives a set of [`DetectionUnit`]s over biological oscillation
+/// streams, tracking the last-evaluated offset per stream so the same
+/// window never re-fires, and POSTs every detection to a [`WebhookSink`].
+pub struct DetectionRunner<W: WebhookSink> {
+    units: Vec<DetectionUnit>,
+    webhook: W,
+    window_len: usize,
+    last_offset: HashMap<String, usize>,
+}
+
+impl<W: WebhookSink> DetectionRunner<W> {
+    /// Create a runner evaluating `units` over a sliding window of the
+    /// last `window_len` samples each time it polls a stream.
+    pub fn new(units: Vec<DetectionUnit>, webhook: W, window_len: usize) -> Self {
+        Self {
+            units,
+            webhook,
+            window_len: window_len.max(1),
+            last_offset: HashMap::new(),
+        }
+    }
+
+    /// Re-evaluate `stream_id` against every configured unit over the
+    /// tail of `signal`, skipping streams that haven't grown since the
+    /// last poll. Any unit that fires produces an [`Alert`] tagged with
+    /// `oscillation_type`/`offending_scale`/`timestamp`/`coordinates`,
+    /// which is POSTed to the webhook before being returned.
+    #[allow(clippy::too_many_arguments)]
+    pub fn poll(
+        &mut self,
+        stream_id: &str,
+        signal: &[f64],
+        oscillation_type: &str,
+        offending_scale: &str,
+        timestamp: &str,
+        coordinates: SCoord,
+    ) -> Result<Vec<Alert>, DetectionError> {
+        let offset = *self.last_offset.get(stream_id).unwrap_or(&0);
+        if signal.len() <= offset {
+            return Ok(Vec::new());
+        }
+
+        let window_start = signal.len().saturating_sub(self.window_len);
+        let window = &signal[window_start..];
+
+        let mut fired = Vec::new();
+        for unit in &self.units {
+            let Some(severity_score) = unit.evaluate(window) else {
+                continue;
+            };
+            let severity = if severity_score > 1.0 {
+                AlertSeverity::Critical
+            } else if severity_score > 0.1 {
+                AlertSeverity::Warning
+            } else {
+                AlertSeverity::Info
+            };
+            let alert = Alert {
+                oscillation_type: oscillation_type.to_string(),
+                offending_scale: offending_scale.to_string(),
+                timestamp: timestamp.to_string(),
+                severity,
+                coordinates,
+            };
+            self.webhook.send(&alert)?;
+            fired.push(alert);
+        }
+
+        self.last_offset.insert(stream_id.to_string(), signal.len());
+        Ok(fired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord() -> SCoord {
+        SCoord::new(0.5, 0.5, 0.5).unwrap()
+    }
+
+    #[test]
+    fn test_threshold_unit_fires_outside_bounds() {
+        let unit = DetectionUnit::Threshold {
+            lower: 0.2,
+            upper: 0.8,
+        };
+        assert!(unit.evaluate(&[0.5, 0.9]).is_some());
+        assert!(unit.evaluate(&[0.5, 0.5]).is_none());
+    }
+
+    #[test]
+    fn test_pattern_unit_fires_on_poor_correlation() {
+        let template = vec![0.0, 1.0, 0.0, 1.0, 0.0];
+        let unit = DetectionUnit::Pattern {
+            template: template.clone(),
+            min_correlation: 0.8,
+        };
+        assert!(unit.evaluate(&template).is_none());
+        assert!(unit.evaluate(&[1.0, 1.0, 1.0, 1.0, 1.0]).is_some());
+    }
+
+    #[test]
+    fn test_seasonal_unit_fires_on_large_deviation() {
+        let unit = DetectionUnit::Seasonal {
+            base_period: 4,
+            z_threshold: 2.0,
+        };
+        let mut steady = vec![1.0, 1.0, 1.0, 1.0];
+        steady.push(1.0);
+        assert!(unit.evaluate(&steady).is_none());
+
+        // The history needs nonzero spread for a z-score to be defined.
+        let mut spiked = vec![1.0, 1.1, 0.9, 1.0];
+        spiked.push(100.0);
+        assert!(unit.evaluate(&spiked).is_some());
+    }
+
+    #[test]
+    fn test_runner_does_not_re_alert_on_unchanged_stream() {
+        let units = vec![DetectionUnit::Threshold {
+            lower: 0.0,
+            upper: 1.0,
+        }];
+        let webhook = RecordingWebhookSink::default();
+        let mut runner = DetectionRunner::new(units, webhook, 8);
+
+        let signal = vec![0.5, 0.5, 5.0];
+        let fired = runner
+            .poll("stream-a", &signal, "heart_rate", "organ_scale", "t0", coord())
+            .unwrap();
+        assert_eq!(fired.len(), 1);
+
+        let fired_again = runner
+            .poll("stream-a", &signal, "heart_rate", "organ_scale", "t0", coord())
+            .unwrap();
+        assert!(fired_again.is_empty());
+    }
+
+    #[test]
+    fn test_runner_sends_alerts_to_webhook() {
+        let units = vec![DetectionUnit::Threshold {
+            lower: 0.0,
+            upper: 1.0,
+        }];
+        let webhook = RecordingWebhookSink::default();
+        let mut runner = DetectionRunner::new(units, webhook, 8);
+
+        runner
+            .poll("stream-a", &[5.0], "heart_rate", "organ_scale", "t0", coord())
+            .unwrap();
+
+        assert_eq!(runner.webhook.sent.lock().unwrap().len(), 1);
+    }
+}
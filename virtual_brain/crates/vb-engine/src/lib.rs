@@ -6,9 +6,23 @@
 //!
 //! - `PoincareComputer`: Main simulation engine for consciousness computation
 //! - `StateManager`: State transition and history management
+//! - `detection`: Real-time anomaly detection over oscillation streams, with webhook alerting
+//! - `pathology_detection`: Streaming pathological-signature recognition against a reference library
 
+pub mod detection;
+pub mod pathology_detection;
 pub mod poincare_computer;
 pub mod state_manager;
 
-pub use poincare_computer::{ComputeResult, PoincareComputer};
-pub use state_manager::{StateManager, TransitionResult};
+pub use detection::{
+    Alert, AlertSeverity, DetectionRunner, DetectionUnit, HttpWebhookSink, RecordingWebhookSink,
+    WebhookSink,
+};
+pub use pathology_detection::{
+    AlertSink, PathologicalSignature, PathologyLibrary, PathologyRunner, ProgressionIndicator,
+    ReferencePattern,
+};
+pub use poincare_computer::{
+    verify_chain, Checkpoint, ComputeResult, PoincareComputer, GENESIS_DIGEST,
+};
+pub use state_manager::{StateManager, StateViolation, TransitionResult};
@@ -2,13 +2,78 @@
 //!
 //! Implements the Poincare computing paradigm for consciousness simulation.
 
+use ndarray::Array1;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use vb_core::constants::{DEFAULT_COUPLING_STRENGTH, DEFAULT_N_OSCILLATORS};
-use vb_core::types::{MentalState, SCoord};
+use vb_core::types::{CircuitRegime, MentalState, SCoord};
 use vb_operators::{
-    coherence, kuramoto, kuramoto_with_drug, navigate, KuramotoState,
+    coherence, evolve_mental_state, kuramoto, kuramoto_with_drug, navigate,
+    spectrum as spectral_summary, KuramotoState, PerceptionSource, Spectrum,
 };
 
+/// Spectral-entropy threshold (nats) below which the recorded coherence
+/// trajectory is classified as phase-locked rather than chaotic — a more
+/// principled frequency-domain counterpart to `CircuitState`'s bare
+/// `variance > 1.0` regime check.
+const CHAOTIC_ENTROPY_THRESHOLD: f64 = 2.0;
+
+/// Digest for a run that hasn't taken any steps yet (the genesis of a
+/// hash chain produced by [`PoincareComputer::run_simulation_checked`]).
+pub const GENESIS_DIGEST: &str = "";
+
+/// A tamper-evident, resumable checkpoint of a `PoincareComputer` run.
+///
+/// Captures the full `KuramotoState`, the tail of `state_history` needed to
+/// resume, the next step index, and the running digest of every step taken
+/// so far — borrowing the step-proving structure of incrementally
+/// verifiable computation. A checkpoint can be serialized, handed to
+/// another process, and continued with [`PoincareComputer::resume`]; the
+/// resulting trajectory's digest can later be checked against this
+/// checkpoint with [`verify_chain`] without re-running the physics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Kuramoto oscillator state at the checkpoint.
+    pub kuramoto_state: KuramotoState,
+    /// Tail of `state_history` retained at the checkpoint.
+    pub state_history_tail: Vec<MentalState>,
+    /// Number of partition levels (carried through on resume).
+    pub n_partition_levels: i32,
+    /// Mean oscillator frequency (carried through on resume).
+    pub mean_frequency: f64,
+    /// Frequency standard deviation (carried through on resume).
+    pub frequency_std: f64,
+    /// Index of the next step to be executed.
+    pub step_index: usize,
+    /// Running digest folding every step taken so far, hex-encoded.
+    pub digest: String,
+}
+
+/// Fold one simulation step into the running hash chain:
+/// `digest_i = SHA256(i ‖ prev_digest ‖ serialize(state_i))`.
+fn fold_digest(step_index: usize, prev_digest: &str, state: &MentalState) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(step_index.to_le_bytes());
+    hasher.update(prev_digest.as_bytes());
+    hasher.update(serde_json::to_vec(state).unwrap_or_default());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Recompute the hash chain over a trajectory produced after `initial`,
+/// confirming it matches `final_digest` without re-running the Kuramoto
+/// physics.
+pub fn verify_chain(initial: &Checkpoint, trajectory: &[MentalState], final_digest: &str) -> bool {
+    let mut digest = initial.digest.clone();
+    for (offset, state) in trajectory.iter().enumerate() {
+        digest = fold_digest(initial.step_index + offset, &digest, state);
+    }
+    digest == final_digest
+}
+
 /// Result of a computation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComputeResult {
@@ -33,6 +98,8 @@ pub struct PoincareComputer {
     kuramoto_state: KuramotoState,
     /// History of mental states
     state_history: Vec<MentalState>,
+    /// Per-region history from the most recent `run_simulation_multi` call
+    region_history: Vec<Vec<MentalState>>,
     /// Number of partition levels
     n_partition_levels: i32,
     /// Mean oscillator frequency
@@ -59,6 +126,7 @@ impl PoincareComputer {
                 coupling_strength,
             ),
             state_history: Vec::new(),
+            region_history: Vec::new(),
             n_partition_levels,
             mean_frequency,
             frequency_std,
@@ -133,6 +201,102 @@ impl PoincareComputer {
         }
     }
 
+    /// Compute consciousness to target level using minimum-time bang-bang
+    /// coupling control, in place of the fixed ±1% nudge used by
+    /// [`Self::compute_consciousness`].
+    ///
+    /// Treats the coupling rate `du/dt` as a control bounded by
+    /// `[-a_max, +a_max]`: by Pontryagin's maximum principle, when the
+    /// control enters the dynamics linearly and is bounded, the time-optimal
+    /// input saturates at its extremes and switches instantaneously on a
+    /// switching surface. Here the switching surface is simply
+    /// `c - target_consciousness = 0`: apply `+a_max` while below target and
+    /// `-a_max` once past it, clamping `coupling_strength` to
+    /// `[k_min, k_max]`. A `deadband` around the target suppresses control
+    /// once `c` is close enough, avoiding chattering between the two
+    /// extremes. Each sign change of the control is recorded as a switching
+    /// time; both the switching times and the realized control trajectory
+    /// are returned via `ComputeResult.metadata`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compute_consciousness_mintime(
+        &mut self,
+        initial_state: &MentalState,
+        target_consciousness: f64,
+        max_iterations: usize,
+        dt: f64,
+        a_max: f64,
+        k_min: f64,
+        k_max: f64,
+        deadband: f64,
+    ) -> ComputeResult {
+        let mut state = initial_state.clone();
+        let mut trajectory = Vec::new();
+        let mut convergence = Vec::new();
+        let mut switching_times = Vec::new();
+        let mut control_trajectory = Vec::new();
+        let mut last_sign: Option<f64> = None;
+        let mut t = 0.0;
+
+        if let Some(s) = state.s_coord {
+            trajectory.push(s);
+        }
+
+        for iter in 0..max_iterations {
+            // Evolve Kuramoto
+            self.kuramoto_state = kuramoto(&self.kuramoto_state, dt);
+            let r = self.current_coherence();
+
+            // Update mental state
+            state = state.with_gamma(r);
+            state = state.decay_perception(0.05, dt);
+            state = state.decay_thought(0.1, dt);
+
+            let c = state.consciousness();
+            convergence.push(c);
+            t += dt;
+
+            let error = c - target_consciousness;
+            if error.abs() < deadband {
+                control_trajectory.push(0.0);
+                return ComputeResult {
+                    success: true,
+                    final_state: state,
+                    iterations: iter + 1,
+                    convergence_history: convergence,
+                    trajectory,
+                    metadata: mintime_metadata(&switching_times, &control_trajectory),
+                };
+            }
+
+            // Bang-bang control: saturate at ±a_max, switching sign on the
+            // `c == target_consciousness` surface.
+            let sign = if error < 0.0 { 1.0 } else { -1.0 };
+            if let Some(prev_sign) = last_sign {
+                if (prev_sign - sign).abs() > f64::EPSILON {
+                    switching_times.push(t);
+                }
+            }
+            last_sign = Some(sign);
+            control_trajectory.push(sign * a_max);
+
+            self.kuramoto_state.coupling_strength =
+                (self.kuramoto_state.coupling_strength + sign * a_max * dt).clamp(k_min, k_max);
+        }
+
+        ComputeResult {
+            success: false,
+            final_state: state,
+            iterations: max_iterations,
+            convergence_history: convergence,
+            trajectory,
+            metadata: {
+                let mut m = mintime_metadata(&switching_times, &control_trajectory);
+                m.insert("reason".to_string(), "max_iterations".to_string());
+                m
+            },
+        }
+    }
+
     /// Navigate categorical space from start to target.
     pub fn navigate_categorical_space(
         &mut self,
@@ -184,6 +348,192 @@ impl PoincareComputer {
         states
     }
 
+    /// Run simulation for given duration, sampling perception/thought drives
+    /// from `source` at each step instead of letting them passively decay.
+    ///
+    /// Otherwise identical to [`Self::run_simulation`]: Kuramoto coherence
+    /// still drives `gamma` via `with_gamma`, but the perception/thought
+    /// decay is replaced with `evolve_mental_state`'s input-driven update,
+    /// the same mechanism `neural_ops::consciousness_time_series_with_source`
+    /// uses for the source-driven consciousness time series.
+    pub fn run_simulation_with_source(
+        &mut self,
+        initial_state: &MentalState,
+        duration: f64,
+        dt: f64,
+        source: &mut dyn PerceptionSource,
+    ) -> Vec<MentalState> {
+        let n_steps = (duration / dt) as usize;
+        let mut states = Vec::with_capacity(n_steps);
+        let mut state = initial_state.clone();
+
+        for _ in 0..n_steps {
+            self.kuramoto_state = kuramoto(&self.kuramoto_state, dt);
+            let r = self.current_coherence();
+
+            state = state.with_gamma(r);
+            let drive = source.sample(state.timestamp, &state);
+            state = evolve_mental_state(&state, dt, drive.perception, drive.thought, 0.0);
+
+            states.push(state.clone());
+        }
+
+        self.state_history = states.clone();
+        states
+    }
+
+    /// Run simulation for given duration, folding a tamper-evident digest
+    /// at each step so the run can be checkpointed, resumed elsewhere, and
+    /// later verified with [`verify_chain`] without re-running the physics.
+    ///
+    /// `start_step` and `start_digest` seed the chain (use `0` and
+    /// [`GENESIS_DIGEST`] for a fresh run, or a [`Checkpoint`]'s
+    /// `step_index`/`digest` to continue one). Returns the produced states
+    /// plus the running digest after the last step.
+    pub fn run_simulation_checked(
+        &mut self,
+        initial_state: &MentalState,
+        duration: f64,
+        dt: f64,
+        start_step: usize,
+        start_digest: &str,
+    ) -> (Vec<MentalState>, String) {
+        let n_steps = (duration / dt) as usize;
+        let mut states = Vec::with_capacity(n_steps);
+        let mut state = initial_state.clone();
+        let mut digest = start_digest.to_string();
+
+        for local_i in 0..n_steps {
+            self.kuramoto_state = kuramoto(&self.kuramoto_state, dt);
+            let r = self.current_coherence();
+
+            state = state.with_gamma(r);
+            state = state.decay_perception(0.05, dt);
+            state = state.decay_thought(0.1, dt);
+            state.timestamp += dt;
+
+            digest = fold_digest(start_step + local_i, &digest, &state);
+            states.push(state.clone());
+        }
+
+        self.state_history = states.clone();
+        (states, digest)
+    }
+
+    /// Snapshot this computer into a resumable, tamper-evident checkpoint.
+    ///
+    /// `step_index`/`digest` are the caller's running chain position
+    /// (typically the second element returned by
+    /// [`Self::run_simulation_checked`]); the checkpoint is otherwise
+    /// self-contained.
+    pub fn snapshot(&self, step_index: usize, digest: &str) -> Checkpoint {
+        Checkpoint {
+            kuramoto_state: self.kuramoto_state.clone(),
+            state_history_tail: self.state_history.clone(),
+            n_partition_levels: self.n_partition_levels,
+            mean_frequency: self.mean_frequency,
+            frequency_std: self.frequency_std,
+            step_index,
+            digest: digest.to_string(),
+        }
+    }
+
+    /// Resume a `PoincareComputer` from a previously taken [`Checkpoint`].
+    pub fn resume(checkpoint: Checkpoint) -> Self {
+        Self {
+            kuramoto_state: checkpoint.kuramoto_state,
+            state_history: checkpoint.state_history_tail,
+            region_history: Vec::new(),
+            n_partition_levels: checkpoint.n_partition_levels,
+            mean_frequency: checkpoint.mean_frequency,
+            frequency_std: checkpoint.frequency_std,
+        }
+    }
+
+    /// Run a multi-region simulation: evolve a vector of coupled
+    /// `MentalState`s, each owning its own `KuramotoState`, with
+    /// `inter_coupling[i][j]` injecting a cross-coherence term from region
+    /// `j`'s bare order parameter into region `i`'s effective order
+    /// parameter before `with_gamma` is applied. `inter_coupling` must be
+    /// `n_regions x n_regions`; the diagonal is unused.
+    ///
+    /// This generalizes `run_simulation` from a single monolithic
+    /// oscillator bank to hierarchical/networked regions, of which
+    /// `run_simulation` is the 1x1 case (a single region with no
+    /// cross-coupling).
+    pub fn run_simulation_multi(
+        &mut self,
+        states: &[MentalState],
+        inter_coupling: &[Vec<f64>],
+        duration: f64,
+        dt: f64,
+    ) -> Vec<Vec<MentalState>> {
+        let n_regions = states.len();
+        assert_eq!(
+            inter_coupling.len(),
+            n_regions,
+            "inter_coupling must have one row per region"
+        );
+        for row in inter_coupling {
+            assert_eq!(
+                row.len(),
+                n_regions,
+                "inter_coupling must be n_regions x n_regions"
+            );
+        }
+
+        let n_steps = (duration / dt) as usize;
+
+        // Each region owns its own oscillator bank, seeded from the
+        // computer's base Kuramoto state so a single region with zero
+        // cross-coupling reproduces `run_simulation`'s dynamics exactly.
+        let mut region_kuramoto: Vec<KuramotoState> =
+            (0..n_regions).map(|_| self.kuramoto_state.clone()).collect();
+        let mut region_states: Vec<MentalState> = states.to_vec();
+        let mut histories: Vec<Vec<MentalState>> =
+            (0..n_regions).map(|_| Vec::with_capacity(n_steps)).collect();
+
+        for _ in 0..n_steps {
+            let bare_coherence: Vec<f64> = region_kuramoto
+                .iter_mut()
+                .map(|ks| {
+                    *ks = kuramoto(ks, dt);
+                    coherence(&ks.phases)
+                })
+                .collect();
+
+            for i in 0..n_regions {
+                let cross_term: f64 = (0..n_regions)
+                    .filter(|&j| j != i)
+                    .map(|j| inter_coupling[i][j] * bare_coherence[j])
+                    .sum();
+                let r_eff = (bare_coherence[i] + cross_term).clamp(0.0, 1.0);
+
+                let mut state = region_states[i].clone();
+                state = state.with_gamma(r_eff);
+                state = state.decay_perception(0.05, dt);
+                state = state.decay_thought(0.1, dt);
+                state.timestamp += dt;
+
+                histories[i].push(state.clone());
+                region_states[i] = state;
+            }
+        }
+
+        if n_regions == 1 {
+            self.kuramoto_state = region_kuramoto.into_iter().next().unwrap();
+            self.state_history = histories[0].clone();
+        }
+        self.region_history = histories.clone();
+
+        histories
+    }
+
+    /// Per-region history from the most recent `run_simulation_multi` call.
+    pub fn region_history(&self) -> &[Vec<MentalState>] {
+        &self.region_history
+    }
+
     /// Find equilibrium state.
     pub fn find_equilibrium(
         &mut self,
@@ -233,6 +583,36 @@ impl PoincareComputer {
         &self.state_history
     }
 
+    /// Frequency-domain diagnostic over the recorded coherence trajectory
+    /// (`state_history`'s `gamma` values): its `n_peaks` dominant
+    /// frequencies/magnitudes plus the spectral entropy of the whole
+    /// non-DC power distribution, via the radix-2 FFT in
+    /// `vb_operators::spectral`.
+    pub fn spectrum(&self, n_peaks: usize) -> Spectrum {
+        let coherence_trajectory =
+            Array1::from_iter(self.state_history.iter().map(|s| s.gamma));
+        let dt = if self.state_history.len() >= 2 {
+            self.state_history[1].timestamp - self.state_history[0].timestamp
+        } else {
+            1.0
+        };
+
+        spectral_summary(&coherence_trajectory, dt, n_peaks)
+    }
+
+    /// Classify the recorded coherence trajectory as phase-locked or
+    /// chaotic from its spectral entropy (low entropy = a few sharp peaks =
+    /// phase-locked; high entropy = power spread broadly = chaotic), a more
+    /// principled diagnostic than `CircuitState`'s bare variance threshold.
+    pub fn spectral_regime(&self) -> CircuitRegime {
+        let spec = self.spectrum(3);
+        if spec.spectral_entropy < CHAOTIC_ENTROPY_THRESHOLD {
+            CircuitRegime::PhaseLocked
+        } else {
+            CircuitRegime::Chaotic
+        }
+    }
+
     /// Reset Kuramoto state.
     pub fn reset_kuramoto(&mut self) {
         self.kuramoto_state = KuramotoState::random(
@@ -244,6 +624,21 @@ impl PoincareComputer {
     }
 }
 
+/// Pack a bang-bang run's switching times and realized control trajectory
+/// into the string-valued metadata map used by [`ComputeResult`].
+fn mintime_metadata(
+    switching_times: &[f64],
+    control_trajectory: &[f64],
+) -> std::collections::HashMap<String, String> {
+    let mut m = std::collections::HashMap::new();
+    m.insert("switching_times".to_string(), format!("{:?}", switching_times));
+    m.insert(
+        "control_trajectory".to_string(),
+        format!("{:?}", control_trajectory),
+    );
+    m
+}
+
 impl Default for PoincareComputer {
     fn default() -> Self {
         Self::new(DEFAULT_N_OSCILLATORS, DEFAULT_COUPLING_STRENGTH, 5)
@@ -270,6 +665,119 @@ mod tests {
         assert_eq!(states.len(), 100);
     }
 
+    #[test]
+    fn test_run_simulation_with_source_applies_sampled_drive() {
+        use vb_operators::ClosurePerceptionSource;
+
+        let initial = MentalState {
+            p_decay: 0.1,
+            ..MentalState::default()
+        };
+
+        let mut driven = PoincareComputer::new(50, 2.0, 5);
+        let mut source = ClosurePerceptionSource::with_default_thought(|_t| 1.0);
+        let driven_states =
+            driven.run_simulation_with_source(&initial, 1.0, 0.01, &mut source);
+        assert_eq!(driven_states.len(), 100);
+
+        let mut undriven = PoincareComputer::new(50, 2.0, 5);
+        let undriven_states = undriven.run_simulation(&initial, 1.0, 0.01);
+
+        // A constant perception_input of 1.0 should pull p_decay up over
+        // the run, unlike `run_simulation`, which only ever decays it.
+        assert!(driven_states.last().unwrap().p_decay > undriven_states.last().unwrap().p_decay);
+    }
+
+    #[test]
+    fn test_compute_consciousness_mintime_terminates_and_reports_controls() {
+        let mut computer = PoincareComputer::new(50, 0.5, 5);
+        let initial = MentalState::default();
+
+        let result = computer.compute_consciousness_mintime(
+            &initial, 0.3, 500, 0.01, 1.0, 0.0, 10.0, 0.01,
+        );
+
+        assert!(result.iterations <= 500);
+        assert!(result.metadata.contains_key("switching_times"));
+        assert!(result.metadata.contains_key("control_trajectory"));
+        if result.success {
+            assert!((result.final_state.consciousness() - 0.3).abs() < 0.05);
+        } else {
+            assert_eq!(result.metadata.get("reason").map(String::as_str), Some("max_iterations"));
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_resume_and_verify_chain() {
+        let mut computer = PoincareComputer::new(30, 1.0, 5);
+        let initial = MentalState::default();
+
+        let (first_half, mid_digest) =
+            computer.run_simulation_checked(&initial, 0.5, 0.01, 0, GENESIS_DIGEST);
+        let checkpoint = computer.snapshot(first_half.len(), &mid_digest);
+
+        let mut resumed = PoincareComputer::resume(checkpoint.clone());
+        let last_state = first_half.last().unwrap().clone();
+        let (second_half, final_digest) = resumed.run_simulation_checked(
+            &last_state,
+            0.5,
+            0.01,
+            checkpoint.step_index,
+            &checkpoint.digest,
+        );
+
+        assert!(verify_chain(&checkpoint, &second_half, &final_digest));
+
+        // Tampering with a single replayed state must break verification.
+        let mut tampered = second_half.clone();
+        tampered[0].gamma = (tampered[0].gamma + 0.5).min(1.0);
+        assert!(!verify_chain(&checkpoint, &tampered, &final_digest));
+    }
+
+    #[test]
+    fn test_run_simulation_multi_reproduces_single_region() {
+        let mut multi = PoincareComputer::new(50, 2.0, 5);
+        let initial = MentalState::default();
+
+        let histories = multi.run_simulation_multi(&[initial], &[vec![0.0]], 1.0, 0.01);
+
+        assert_eq!(histories.len(), 1);
+        assert_eq!(histories[0].len(), 100);
+        assert_eq!(multi.region_history().len(), 1);
+    }
+
+    #[test]
+    fn test_run_simulation_multi_cross_couples_regions() {
+        let mut computer = PoincareComputer::new(50, 2.0, 5);
+        let states = vec![MentalState::default(), MentalState::default()];
+        let inter_coupling = vec![vec![0.0, 0.5], vec![0.5, 0.0]];
+
+        let histories = computer.run_simulation_multi(&states, &inter_coupling, 0.5, 0.01);
+
+        assert_eq!(histories.len(), 2);
+        assert_eq!(histories[0].len(), 50);
+        assert_eq!(histories[1].len(), 50);
+        for region in &histories {
+            for state in region {
+                assert!(state.gamma >= 0.0 && state.gamma <= 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_spectrum_and_spectral_regime_from_state_history() {
+        let mut computer = PoincareComputer::new(50, 2.0, 5);
+        let initial = MentalState::default();
+        computer.run_simulation(&initial, 1.0, 0.01);
+
+        let spec = computer.spectrum(3);
+        assert!(spec.dominant_frequencies.len() <= 3);
+        assert!(spec.spectral_entropy >= 0.0);
+
+        let regime = computer.spectral_regime();
+        assert!(matches!(regime, CircuitRegime::PhaseLocked | CircuitRegime::Chaotic));
+    }
+
     #[test]
     fn test_navigate() {
         let mut computer = PoincareComputer::default();
@@ -1,6 +1,7 @@
 //! State Manager: Tracks state transitions and history.
 
 use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{self, Receiver, Sender};
 use vb_core::types::{MentalState, PartitionCoord, SCoord};
 
 /// Result of a state transition.
@@ -18,6 +19,28 @@ pub struct TransitionResult {
     pub metadata: std::collections::HashMap<String, String>,
 }
 
+/// A single out-of-range field detected by [`StateManager::validate_detailed`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateViolation {
+    /// Dotted path to the offending field (e.g. "gamma", "s_coord.sk").
+    pub field: String,
+    /// The out-of-range value that was found.
+    pub value: f64,
+    /// The valid range, inclusive.
+    pub valid_range: (f64, f64),
+}
+
+/// Record a [`StateViolation`] for `field` if `value` falls outside [0, 1].
+fn push_if_out_of_bounds(violations: &mut Vec<StateViolation>, field: &str, value: f64) {
+    if !(0.0..=1.0).contains(&value) {
+        violations.push(StateViolation {
+            field: field.to_string(),
+            value,
+            valid_range: (0.0, 1.0),
+        });
+    }
+}
+
 /// Manages state transitions and history.
 #[derive(Debug, Clone)]
 pub struct StateManager {
@@ -27,6 +50,8 @@ pub struct StateManager {
     history: Vec<MentalState>,
     /// Maximum history size
     max_history: usize,
+    /// Live subscribers notified of every `TransitionResult` produced.
+    subscribers: Vec<Sender<TransitionResult>>,
 }
 
 impl StateManager {
@@ -36,9 +61,26 @@ impl StateManager {
             current_state: MentalState::default(),
             history: Vec::new(),
             max_history,
+            subscribers: Vec::new(),
         }
     }
 
+    /// Subscribe to a live stream of every `TransitionResult` this manager
+    /// produces (including dream/wake transitions). Lets a dashboard or
+    /// logger consume transitions without polling `history`. Subscribers
+    /// whose receiver has been dropped are pruned on the next broadcast.
+    pub fn subscribe(&mut self) -> Receiver<TransitionResult> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Broadcast `result` to every live subscriber, dropping senders
+    /// whose receiver has gone away.
+    fn broadcast(&mut self, result: &TransitionResult) {
+        self.subscribers.retain(|tx| tx.send(result.clone()).is_ok());
+    }
+
     /// Initialize with a specific state.
     pub fn initialize(&mut self, s_coord: Option<SCoord>, partition: Option<PartitionCoord>) {
         self.current_state = MentalState::initial(s_coord, partition);
@@ -86,47 +128,92 @@ impl StateManager {
 
         self.current_state = new_state;
 
-        TransitionResult {
+        let result = TransitionResult {
             success: true,
             from_state,
             to_state: target_s_coord,
             distance,
             metadata: std::collections::HashMap::new(),
+        };
+        self.broadcast(&result);
+        result
+    }
+
+    /// Async counterpart to [`Self::transition`]: performs the same
+    /// mutation and broadcast, then awaits an optional per-transition
+    /// settling hook (e.g. a downstream write or rate-limiting delay)
+    /// before returning, so callers can back-pressure long trajectories.
+    pub async fn transition_async<F, Fut>(
+        &mut self,
+        target_s_coord: SCoord,
+        new_gamma: Option<f64>,
+        new_gamma_f: Option<f64>,
+        settle: Option<F>,
+    ) -> TransitionResult
+    where
+        F: FnOnce(&TransitionResult) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let result = self.transition(target_s_coord, new_gamma, new_gamma_f);
+        if let Some(settle) = settle {
+            settle(&result).await;
         }
+        result
     }
 
     /// Validate current state.
     pub fn validate_state(&self) -> bool {
+        self.validate_detailed().is_empty()
+    }
+
+    /// Validate current state, reporting every out-of-bounds field (with
+    /// its value and valid range) instead of a bare `bool`.
+    pub fn validate_detailed(&self) -> Vec<StateViolation> {
         let state = &self.current_state;
+        let mut violations = Vec::new();
 
-        // Check bounds
-        if state.gamma < 0.0 || state.gamma > 1.0 {
-            return false;
-        }
-        if state.gamma_f < 0.0 || state.gamma_f > 1.0 {
-            return false;
+        push_if_out_of_bounds(&mut violations, "gamma", state.gamma);
+        push_if_out_of_bounds(&mut violations, "gamma_f", state.gamma_f);
+        push_if_out_of_bounds(&mut violations, "p_decay", state.p_decay);
+        push_if_out_of_bounds(&mut violations, "t_decay", state.t_decay);
+
+        if let Some(s) = &state.s_coord {
+            push_if_out_of_bounds(&mut violations, "s_coord.sk", s.sk);
+            push_if_out_of_bounds(&mut violations, "s_coord.st", s.st);
+            push_if_out_of_bounds(&mut violations, "s_coord.se", s.se);
         }
-        if state.p_decay < 0.0 || state.p_decay > 1.0 {
-            return false;
+
+        violations
+    }
+
+    /// Clamp every out-of-bounds field of the current state back into
+    /// range, record the correction as a new `history` entry, and return
+    /// what was changed. A safe recovery path that doesn't require callers
+    /// to manually reconstruct a `MentalState`.
+    pub fn repair(&mut self) -> Vec<StateViolation> {
+        let violations = self.validate_detailed();
+        if violations.is_empty() {
+            return violations;
         }
-        if state.t_decay < 0.0 || state.t_decay > 1.0 {
-            return false;
+
+        let mut state = self.current_state.clone();
+        state.gamma = state.gamma.clamp(0.0, 1.0);
+        state.gamma_f = state.gamma_f.clamp(0.0, 1.0);
+        state.p_decay = state.p_decay.clamp(0.0, 1.0);
+        state.t_decay = state.t_decay.clamp(0.0, 1.0);
+        if let Some(s) = &mut state.s_coord {
+            s.sk = s.sk.clamp(0.0, 1.0);
+            s.st = s.st.clamp(0.0, 1.0);
+            s.se = s.se.clamp(0.0, 1.0);
         }
 
-        // Check S-coordinate bounds if present
-        if let Some(s) = &state.s_coord {
-            if s.sk < 0.0 || s.sk > 1.0 {
-                return false;
-            }
-            if s.st < 0.0 || s.st > 1.0 {
-                return false;
-            }
-            if s.se < 0.0 || s.se > 1.0 {
-                return false;
-            }
+        self.current_state = state.clone();
+        self.history.push(state);
+        if self.history.len() > self.max_history {
+            self.history.remove(0);
         }
 
-        true
+        violations
     }
 
     /// Extract S-coordinate trajectory.
@@ -177,7 +264,7 @@ impl StateManager {
             self.history.remove(0);
         }
 
-        TransitionResult {
+        let result = TransitionResult {
             success: true,
             from_state,
             to_state: self.current_state.s_coord.unwrap_or(SCoord::origin()),
@@ -187,7 +274,9 @@ impl StateManager {
                 m.insert("mode".to_string(), "dream".to_string());
                 m
             },
-        }
+        };
+        self.broadcast(&result);
+        result
     }
 
     /// Wake up from dream.
@@ -200,7 +289,7 @@ impl StateManager {
             self.history.remove(0);
         }
 
-        TransitionResult {
+        let result = TransitionResult {
             success: true,
             from_state,
             to_state: self.current_state.s_coord.unwrap_or(SCoord::origin()),
@@ -210,7 +299,9 @@ impl StateManager {
                 m.insert("mode".to_string(), "awake".to_string());
                 m
             },
-        }
+        };
+        self.broadcast(&result);
+        result
     }
 
     /// Clear history.
@@ -219,6 +310,92 @@ impl StateManager {
         self.history.push(self.current_state.clone());
     }
 
+    /// Pop `n` states from history and restore `current_state` to the
+    /// state that was current before them, keeping at least one entry
+    /// (the earliest snapshot is never rewound past).
+    pub fn rewind(&mut self, n: usize) -> TransitionResult {
+        let from_state = self.current_state.s_coord;
+
+        for _ in 0..n {
+            if self.history.len() <= 1 {
+                break;
+            }
+            self.history.pop();
+        }
+        self.current_state = self.history.last().cloned().unwrap_or_default();
+
+        let to_state = self.current_state.s_coord.unwrap_or(SCoord::origin());
+        let distance = from_state.map(|s| s.distance(&to_state)).unwrap_or(0.0);
+
+        let result = TransitionResult {
+            success: true,
+            from_state,
+            to_state,
+            distance,
+            metadata: {
+                let mut m = std::collections::HashMap::new();
+                m.insert("mode".to_string(), "rewind".to_string());
+                m
+            },
+        };
+        self.broadcast(&result);
+        result
+    }
+
+    /// Revert to the state matched by [`Self::find_state_at_time`],
+    /// truncating history to that point. Reports `success: false` and
+    /// leaves the timeline untouched if no state matches `t`.
+    pub fn restore_at_time(&mut self, t: f64) -> TransitionResult {
+        let from_state = self.current_state.s_coord;
+        let metadata = {
+            let mut m = std::collections::HashMap::new();
+            m.insert("mode".to_string(), "rewind".to_string());
+            m
+        };
+
+        let Some(idx) = self.history.iter().position(|s| (s.timestamp - t).abs() < 1e-6) else {
+            let result = TransitionResult {
+                success: false,
+                from_state,
+                to_state: from_state.unwrap_or(SCoord::origin()),
+                distance: 0.0,
+                metadata,
+            };
+            self.broadcast(&result);
+            return result;
+        };
+
+        self.history.truncate(idx + 1);
+        self.current_state = self.history[idx].clone();
+
+        let to_state = self.current_state.s_coord.unwrap_or(SCoord::origin());
+        let distance = from_state.map(|s| s.distance(&to_state)).unwrap_or(0.0);
+
+        let result = TransitionResult {
+            success: true,
+            from_state,
+            to_state,
+            distance,
+            metadata,
+        };
+        self.broadcast(&result);
+        result
+    }
+
+    /// Snapshot the current timeline into an independent `StateManager`
+    /// that can be inspected or mutated without affecting this one.
+    pub fn checkpoint(&self) -> StateManager {
+        self.clone()
+    }
+
+    /// Branch the current timeline into an independent `StateManager` so
+    /// callers can explore alternative transitions without mutating the
+    /// original. Equivalent to [`Self::checkpoint`], named for the
+    /// branching use case.
+    pub fn fork(&self) -> StateManager {
+        self.clone()
+    }
+
     /// Get summary statistics.
     pub fn summary(&self) -> std::collections::HashMap<String, f64> {
         let mut summary = std::collections::HashMap::new();
@@ -296,4 +473,183 @@ mod tests {
 
         assert!(manager.history().len() <= 6); // 5 + initial
     }
+
+    #[test]
+    fn test_validate_detailed_reports_out_of_bounds_fields() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        manager.current_state.gamma = 1.5;
+        manager.current_state.p_decay = -0.2;
+
+        let violations = manager.validate_detailed();
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().any(|v| v.field == "gamma" && v.value == 1.5));
+        assert!(violations.iter().any(|v| v.field == "p_decay" && v.value == -0.2));
+        assert!(!manager.validate_state());
+    }
+
+    #[test]
+    fn test_repair_clamps_fields_and_records_history() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        let history_len_before = manager.history().len();
+        manager.current_state.gamma = 2.0;
+        manager.current_state.t_decay = -1.0;
+
+        let violations = manager.repair();
+        assert_eq!(violations.len(), 2);
+        assert_eq!(manager.current().gamma, 1.0);
+        assert_eq!(manager.current().t_decay, 0.0);
+        assert!(manager.validate_state());
+        assert_eq!(manager.history().len(), history_len_before + 1);
+    }
+
+    #[test]
+    fn test_repair_is_noop_on_valid_state() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        let history_len_before = manager.history().len();
+
+        assert!(manager.repair().is_empty());
+        assert_eq!(manager.history().len(), history_len_before);
+    }
+
+    #[test]
+    fn test_rewind_restores_earlier_state() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        manager.transition(SCoord::new(0.3, 0.3, 0.3).unwrap(), None, None);
+        let midpoint = manager.current().s_coord;
+        manager.transition(SCoord::new(0.8, 0.8, 0.8).unwrap(), None, None);
+
+        let result = manager.rewind(1);
+        assert!(result.success);
+        assert_eq!(result.metadata["mode"], "rewind");
+        assert_eq!(manager.current().s_coord, midpoint);
+    }
+
+    #[test]
+    fn test_rewind_stops_at_earliest_state() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        manager.transition(SCoord::new(0.3, 0.3, 0.3).unwrap(), None, None);
+
+        manager.rewind(100);
+        assert_eq!(manager.history().len(), 1);
+        assert_eq!(manager.current().s_coord, Some(SCoord::origin()));
+    }
+
+    #[test]
+    fn test_restore_at_time_truncates_history() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        manager.transition(SCoord::new(0.3, 0.3, 0.3).unwrap(), None, None);
+        manager.transition(SCoord::new(0.8, 0.8, 0.8).unwrap(), None, None);
+        // `transition` leaves `timestamp` unchanged, so give each history
+        // entry a distinct time to restore to.
+        for (i, state) in manager.history.iter_mut().enumerate() {
+            state.timestamp = i as f64;
+        }
+        let target_time = 1.0;
+
+        let result = manager.restore_at_time(target_time);
+        assert!(result.success);
+        assert_eq!(manager.current().timestamp, target_time);
+        assert_eq!(manager.history().len(), 2);
+    }
+
+    #[test]
+    fn test_restore_at_time_fails_for_unknown_time() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+
+        let result = manager.restore_at_time(999.0);
+        assert!(!result.success);
+        assert_eq!(manager.history().len(), 1);
+    }
+
+    #[test]
+    fn test_checkpoint_and_fork_are_independent_of_original() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+
+        let mut branch = manager.fork();
+        branch.transition(SCoord::new(0.9, 0.9, 0.9).unwrap(), None, None);
+
+        assert_ne!(branch.current().s_coord, manager.current().s_coord);
+        assert_eq!(manager.history().len(), 1);
+    }
+
+    #[test]
+    fn test_subscribe_receives_every_transition() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        let rx = manager.subscribe();
+
+        manager.transition(SCoord::new(0.5, 0.5, 0.5).unwrap(), None, None);
+        manager.enter_dream_mode();
+        manager.wake_up(0.8);
+
+        let first = rx.try_recv().unwrap();
+        assert!(first.metadata.is_empty());
+        let second = rx.try_recv().unwrap();
+        assert_eq!(second.metadata["mode"], "dream");
+        let third = rx.try_recv().unwrap();
+        assert_eq!(third.metadata["mode"], "awake");
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_dropped_subscriber_is_pruned_on_next_broadcast() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+        drop(manager.subscribe());
+
+        manager.transition(SCoord::new(0.5, 0.5, 0.5).unwrap(), None, None);
+        assert!(manager.subscribers.is_empty());
+    }
+
+    /// Busy-poll executor for driving the trivially-ready futures used by
+    /// `transition_async`'s settling hook in tests, without depending on
+    /// an async runtime crate.
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        use std::future::Future;
+        use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+        fn noop(_: *const ()) {}
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        let raw_waker = RawWaker::new(std::ptr::null(), &VTABLE);
+        let waker = unsafe { Waker::from_raw(raw_waker) };
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+                return value;
+            }
+        }
+    }
+
+    #[test]
+    fn test_transition_async_awaits_settling_hook() {
+        let mut manager = StateManager::new(100);
+        manager.initialize(Some(SCoord::origin()), None);
+
+        let settled = std::cell::Cell::new(false);
+        let result = block_on(manager.transition_async(
+            SCoord::new(0.4, 0.4, 0.4).unwrap(),
+            None,
+            None,
+            Some(|_: &TransitionResult| {
+                settled.set(true);
+                std::future::ready(())
+            }),
+        ));
+
+        assert!(result.success);
+        assert!(settled.get());
+    }
 }
@@ -0,0 +1,41 @@
+//! Benchmarks for the lane-chunked batch kernels, sweeping signal
+//! length to make per-element throughput regressions visible.
+//!
+//! Run with `cargo bench -p vb-operators --bench batch_kernels_bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use vb_operators::{signal_entropy, spectral_centroid};
+
+const SIZES: &[usize] = &[1, 10, 100, 1_000, 10_000, 100_000];
+
+fn sample_signal(n: usize) -> Vec<f64> {
+    (0..n).map(|i| (i as f64 * 0.017).sin() * 10.0 + 1.0).collect()
+}
+
+fn bench_signal_entropy(c: &mut Criterion) {
+    let mut group = c.benchmark_group("signal_entropy");
+    for &n in SIZES {
+        let signal = sample_signal(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &signal, |b, signal| {
+            b.iter(|| signal_entropy(black_box(signal)));
+        });
+    }
+    group.finish();
+}
+
+fn bench_spectral_centroid(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spectral_centroid");
+    for &n in SIZES {
+        let frequencies: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let power = sample_signal(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &(frequencies, power), |b, (frequencies, power)| {
+            b.iter(|| spectral_centroid(black_box(frequencies), black_box(power)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_signal_entropy, bench_spectral_centroid);
+criterion_main!(benches);
@@ -0,0 +1,222 @@
+//! Batch Kernels: lane-chunked numeric kernels for processing many
+//! signals' entropy/spectral-centroid/connectivity in lockstep, instead
+//! of one scalar loop per signal.
+//!
+//! This crate has no `std::simd`/external-SIMD dependency anywhere, so
+//! rather than reach for one, these kernels use the classic
+//! autovectorization-friendly layout: accumulate into several
+//! independent lane accumulators (breaking the serial dependency chain
+//! a naive running sum has) and fold the lanes together at the end,
+//! with a scalar remainder loop for the ragged tail. The `scalar-fallback`
+//! feature switches to a single-lane (`LANES = 1`) reduction instead,
+//! which collapses to a plain sequential loop.
+//!
+//! Reordering floating-point additions is not associativity-preserving,
+//! so the lane-chunked and single-lane reductions can differ by a few
+//! ULPs even though they compute the same mathematical quantity; tests
+//! below check near-equality, not bit-for-bit equality, for that reason.
+
+const LANES: usize = 8;
+
+#[cfg(not(feature = "scalar-fallback"))]
+const REDUCTION_LANES: usize = LANES;
+#[cfg(feature = "scalar-fallback")]
+const REDUCTION_LANES: usize = 1;
+
+fn chunked_sum(values: &[f64]) -> f64 {
+    let mut lanes = [0.0_f64; LANES];
+    let width = REDUCTION_LANES;
+    let chunks = values.chunks_exact(width);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (lane, &v) in lanes[..width].iter_mut().zip(chunk.iter()) {
+            *lane += v;
+        }
+    }
+    let mut total: f64 = lanes[..width].iter().sum();
+    for &v in remainder {
+        total += v;
+    }
+    total
+}
+
+fn chunked_weighted_sum(values: &[f64], weight_of: impl Fn(f64) -> f64) -> f64 {
+    let mut lanes = [0.0_f64; LANES];
+    let width = REDUCTION_LANES;
+    let chunks = values.chunks_exact(width);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (lane, &v) in lanes[..width].iter_mut().zip(chunk.iter()) {
+            *lane += weight_of(v);
+        }
+    }
+    let mut total: f64 = lanes[..width].iter().sum();
+    for &v in remainder {
+        total += weight_of(v);
+    }
+    total
+}
+
+/// Shannon entropy (nats) of a signal's normalized magnitude
+/// distribution: `|x_i| / sum(|x|)`. Zero for an empty or all-zero
+/// signal.
+pub fn signal_entropy(signal: &[f64]) -> f64 {
+    let magnitudes: Vec<f64> = signal.iter().map(|x| x.abs()).collect();
+    let total = chunked_sum(&magnitudes);
+    if total <= 0.0 {
+        return 0.0;
+    }
+    -chunked_weighted_sum(&magnitudes, |m| {
+        if m <= 0.0 {
+            0.0
+        } else {
+            let p = m / total;
+            p * p.ln()
+        }
+    })
+}
+
+/// Power-weighted spectral centroid: `sum(freq_i * power_i) /
+/// sum(power_i)`. `frequencies` and `power` must be the same length;
+/// returns `0.0` for an empty spectrum or zero total power.
+pub fn spectral_centroid(frequencies: &[f64], power: &[f64]) -> f64 {
+    let n = frequencies.len().min(power.len());
+    if n == 0 {
+        return 0.0;
+    }
+    let total_power = chunked_sum(&power[..n]);
+    if total_power <= 0.0 {
+        return 0.0;
+    }
+
+    let mut lanes = [0.0_f64; LANES];
+    let width = REDUCTION_LANES;
+    let mut i = 0;
+    while i + width <= n {
+        for lane in 0..width {
+            lanes[lane] += frequencies[i + lane] * power[i + lane];
+        }
+        i += width;
+    }
+    let mut weighted_sum: f64 = lanes[..width].iter().sum();
+    while i < n {
+        weighted_sum += frequencies[i] * power[i];
+        i += 1;
+    }
+
+    weighted_sum / total_power
+}
+
+/// Entropy of many signals' magnitude distributions, processed one
+/// signal per call but sharing the same vectorized inner kernel.
+pub fn batch_signal_entropy(signals: &[Vec<f64>]) -> Vec<f64> {
+    signals.iter().map(|signal| signal_entropy(signal)).collect()
+}
+
+/// Spectral centroid of many power spectra sharing a common frequency
+/// grid.
+pub fn batch_spectral_centroid(frequencies: &[f64], power_spectra: &[Vec<f64>]) -> Vec<f64> {
+    power_spectra
+        .iter()
+        .map(|power| spectral_centroid(frequencies, power))
+        .collect()
+}
+
+/// Network connectivity (`edges / max(nodes, 1)`) for many networks at
+/// once, processed in lockstep across lanes since this reduction is
+/// elementwise across the batch rather than within one network.
+pub fn batch_network_connectivity(node_counts: &[usize], edge_counts: &[usize]) -> Vec<f64> {
+    let n = node_counts.len().min(edge_counts.len());
+    let mut out = vec![0.0_f64; n];
+
+    let width = REDUCTION_LANES.min(LANES);
+    let mut i = 0;
+    while i + width <= n {
+        for lane in 0..width {
+            let nodes = node_counts[i + lane] as f64;
+            let edges = edge_counts[i + lane] as f64;
+            out[i + lane] = edges / nodes.max(1.0);
+        }
+        i += width;
+    }
+    while i < n {
+        let nodes = node_counts[i] as f64;
+        let edges = edge_counts[i] as f64;
+        out[i] = edges / nodes.max(1.0);
+        i += 1;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signal_entropy_zero_for_empty_and_zero_signals() {
+        assert_eq!(signal_entropy(&[]), 0.0);
+        assert_eq!(signal_entropy(&[0.0, 0.0, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn test_signal_entropy_higher_for_uniform_than_peaked_signal() {
+        let uniform = vec![1.0; 16];
+        let mut peaked = vec![0.01; 16];
+        peaked[0] = 100.0;
+
+        assert!(signal_entropy(&uniform) > signal_entropy(&peaked));
+    }
+
+    #[test]
+    fn test_signal_entropy_matches_naive_sequential_within_epsilon() {
+        let signal: Vec<f64> = (1..=37).map(|i| i as f64 * 0.37).collect();
+        let vectorized = signal_entropy(&signal);
+
+        let total: f64 = signal.iter().map(|x| x.abs()).sum();
+        let naive = -signal
+            .iter()
+            .map(|x| {
+                let p = x.abs() / total;
+                p * p.ln()
+            })
+            .sum::<f64>();
+
+        assert!((vectorized - naive).abs() < 1e-9, "vectorized={vectorized} naive={naive}");
+    }
+
+    #[test]
+    fn test_spectral_centroid_matches_naive_for_ragged_length() {
+        let frequencies: Vec<f64> = (0..21).map(|i| i as f64).collect();
+        let power: Vec<f64> = (0..21).map(|i| (i as f64 + 1.0).recip()).collect();
+
+        let vectorized = spectral_centroid(&frequencies, &power);
+        let total_power: f64 = power.iter().sum();
+        let naive: f64 = frequencies.iter().zip(power.iter()).map(|(f, p)| f * p).sum::<f64>() / total_power;
+
+        assert!((vectorized - naive).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spectral_centroid_zero_for_empty_spectrum() {
+        assert_eq!(spectral_centroid(&[], &[]), 0.0);
+    }
+
+    #[test]
+    fn test_batch_signal_entropy_preserves_order() {
+        let signals = vec![vec![1.0, 1.0, 1.0, 1.0], vec![100.0, 0.01, 0.01, 0.01]];
+        let entropies = batch_signal_entropy(&signals);
+        assert_eq!(entropies.len(), 2);
+        assert!(entropies[0] > entropies[1]);
+    }
+
+    #[test]
+    fn test_batch_network_connectivity_handles_ragged_batch_size() {
+        let node_counts = vec![1, 2, 4, 5, 10, 3, 7, 9, 11];
+        let edge_counts = vec![1, 2, 4, 5, 10, 3, 7, 9, 22];
+        let ratios = batch_network_connectivity(&node_counts, &edge_counts);
+        assert_eq!(ratios.len(), node_counts.len());
+        assert!((ratios[0] - 1.0).abs() < 1e-12);
+        assert!((ratios[8] - 2.0).abs() < 1e-12);
+    }
+}
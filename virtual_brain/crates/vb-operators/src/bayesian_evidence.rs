@@ -0,0 +1,243 @@
+//! Bayesian Evidence: calibrated posterior probabilities over a small
+//! set of latent health regimes, replacing placeholder scalars (like a
+//! hard-coded evidence-quality constant) with a real generative model.
+//!
+//! Each regime has a prior and a likelihood over an observed activity
+//! level, parameterized by a latent nuisance variable `theta` (the
+//! underlying, ATP-constrained activity level). The posterior for an
+//! observation is obtained by marginalizing the likelihood over a grid
+//! of `theta` weighted by its own prior, then normalizing across
+//! regimes.
+
+use std::f64::consts::PI;
+
+/// A latent health regime for cellular/pathology evidence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Regime {
+    Healthy,
+    PrePathological,
+    Pathological,
+}
+
+/// ATP-availability constraints bounding the latent activity-level
+/// nuisance variable `theta`.
+#[derive(Debug, Clone, Copy)]
+pub struct AtpConstraints {
+    pub available_atp: f64,
+    pub atp_cost_per_operation: f64,
+    pub energy_efficiency_threshold: f64,
+}
+
+/// A regime's prior and its likelihood model: observed activity is
+/// assumed Gaussian around `mean_activity * theta` with `std_dev`.
+#[derive(Debug, Clone, Copy)]
+pub struct RegimeModel {
+    pub regime: Regime,
+    pub prior: f64,
+    pub mean_activity: f64,
+    pub std_dev: f64,
+}
+
+fn gaussian_pdf(x: f64, mean: f64, std_dev: f64) -> f64 {
+    if std_dev <= 0.0 {
+        return 0.0;
+    }
+    let z = (x - mean) / std_dev;
+    (-0.5 * z * z).exp() / (std_dev * (2.0 * PI).sqrt())
+}
+
+/// A uniform grid of `n` latent activity-level values in
+/// `(0, energy_efficiency_threshold]` (the threshold clamped to `[0, 1]`).
+/// Only `energy_efficiency_threshold` bounds `theta`; `available_atp` and
+/// `atp_cost_per_operation` do not affect the grid.
+pub fn theta_grid_from_atp(constraints: &AtpConstraints, n: usize) -> Vec<f64> {
+    let theta_max = constraints.energy_efficiency_threshold.clamp(0.0, 1.0).max(1e-6);
+    let n = n.max(1);
+    (0..n).map(|i| theta_max * (i as f64 + 1.0) / n as f64).collect()
+}
+
+/// Posterior `P(regime | observed)` for every regime, via grid
+/// marginalization over `theta` (uniformly weighted) followed by
+/// normalization across regimes. Falls back to a uniform posterior if
+/// every regime's marginal likelihood is zero.
+pub fn posterior(observed: f64, regimes: &[RegimeModel], theta_grid: &[f64]) -> Vec<(Regime, f64)> {
+    if regimes.is_empty() {
+        return Vec::new();
+    }
+
+    let marginal_likelihoods: Vec<f64> = regimes
+        .iter()
+        .map(|model| {
+            if theta_grid.is_empty() {
+                return 0.0;
+            }
+            theta_grid
+                .iter()
+                .map(|&theta| gaussian_pdf(observed, model.mean_activity * theta, model.std_dev))
+                .sum::<f64>()
+                / theta_grid.len() as f64
+        })
+        .collect();
+
+    let weighted: Vec<f64> = regimes
+        .iter()
+        .zip(marginal_likelihoods.iter())
+        .map(|(model, &likelihood)| model.prior * likelihood)
+        .collect();
+
+    let total: f64 = weighted.iter().sum();
+    if total <= 0.0 {
+        let uniform = 1.0 / regimes.len() as f64;
+        return regimes.iter().map(|model| (model.regime, uniform)).collect();
+    }
+
+    regimes
+        .iter()
+        .zip(weighted.iter())
+        .map(|(model, &w)| (model.regime, w / total))
+        .collect()
+}
+
+/// Posterior mass on a single `target` regime (`confidence_level` for
+/// that declared event).
+pub fn event_confidence(posterior: &[(Regime, f64)], target: Regime) -> f64 {
+    posterior.iter().find(|(regime, _)| *regime == target).map(|(_, p)| *p).unwrap_or(0.0)
+}
+
+/// `severity_score`: posterior mass specifically on the
+/// [`Regime::Pathological`] regime.
+pub fn severity_score(posterior: &[(Regime, f64)]) -> f64 {
+    event_confidence(posterior, Regime::Pathological)
+}
+
+/// Cellular evidence quality: how concentrated (confident) the
+/// posterior is, i.e. its maximum mass. Replaces the old hard-coded
+/// `1.2` placeholder with a real `[0, 1]`-bounded quantity.
+pub fn cellular_evidence_quality(posterior: &[(Regime, f64)]) -> f64 {
+    posterior.iter().map(|(_, p)| *p).fold(0.0_f64, f64::max)
+}
+
+/// Benjamini-Hochberg-style Bayesian FDR control: sorts
+/// `error_probabilities` (`1 - posterior` for each window's declared
+/// event) ascending, and returns the original indices of the largest
+/// prefix whose running mean error probability stays at or below
+/// `target_fdr`.
+///
+/// This prefix is well-defined because, for values sorted ascending,
+/// the running mean is non-decreasing in prefix length (each added
+/// value is at least as large as the mean preceding it), so the first
+/// violation marks the cutoff.
+pub fn fdr_controlled_selection(error_probabilities: &[f64], target_fdr: f64) -> Vec<usize> {
+    let mut indexed: Vec<(usize, f64)> = error_probabilities.iter().copied().enumerate().collect();
+    indexed.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut selected = Vec::new();
+    let mut running_sum = 0.0;
+    for (rank, &(original_index, error_probability)) in indexed.iter().enumerate() {
+        running_sum += error_probability;
+        let running_mean = running_sum / (rank + 1) as f64;
+        if running_mean > target_fdr {
+            break;
+        }
+        selected.push(original_index);
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regimes() -> Vec<RegimeModel> {
+        vec![
+            RegimeModel {
+                regime: Regime::Healthy,
+                prior: 0.6,
+                mean_activity: 0.2,
+                std_dev: 0.1,
+            },
+            RegimeModel {
+                regime: Regime::PrePathological,
+                prior: 0.3,
+                mean_activity: 0.5,
+                std_dev: 0.1,
+            },
+            RegimeModel {
+                regime: Regime::Pathological,
+                prior: 0.1,
+                mean_activity: 0.9,
+                std_dev: 0.1,
+            },
+        ]
+    }
+
+    fn theta_grid() -> Vec<f64> {
+        let constraints = AtpConstraints {
+            available_atp: 100.0,
+            atp_cost_per_operation: 1.0,
+            energy_efficiency_threshold: 1.0,
+        };
+        theta_grid_from_atp(&constraints, 50)
+    }
+
+    #[test]
+    fn test_posterior_sums_to_one() {
+        let post = posterior(0.5, &regimes(), &theta_grid());
+        let total: f64 = post.iter().map(|(_, p)| *p).sum();
+        assert!((total - 1.0).abs() < 1e-9, "total = {total}");
+    }
+
+    #[test]
+    fn test_posterior_favors_matching_regime() {
+        let post = posterior(0.9, &regimes(), &theta_grid());
+        let pathological = event_confidence(&post, Regime::Pathological);
+        let healthy = event_confidence(&post, Regime::Healthy);
+        assert!(pathological > healthy, "pathological={pathological} healthy={healthy}");
+    }
+
+    #[test]
+    fn test_severity_score_matches_pathological_posterior() {
+        let post = posterior(0.9, &regimes(), &theta_grid());
+        assert_eq!(severity_score(&post), event_confidence(&post, Regime::Pathological));
+    }
+
+    #[test]
+    fn test_cellular_evidence_quality_is_bounded() {
+        let post = posterior(0.2, &regimes(), &theta_grid());
+        let quality = cellular_evidence_quality(&post);
+        assert!((0.0..=1.0).contains(&quality));
+    }
+
+    #[test]
+    fn test_empty_regimes_yields_empty_posterior() {
+        assert!(posterior(0.5, &[], &theta_grid()).is_empty());
+    }
+
+    #[test]
+    fn test_theta_grid_bounded_by_efficiency_threshold() {
+        let constraints = AtpConstraints {
+            available_atp: 100.0,
+            atp_cost_per_operation: 1.0,
+            energy_efficiency_threshold: 0.4,
+        };
+        let grid = theta_grid_from_atp(&constraints, 10);
+        assert!(grid.iter().all(|&theta| theta <= 0.4 + 1e-9));
+        assert_eq!(grid.len(), 10);
+    }
+
+    #[test]
+    fn test_fdr_controlled_selection_respects_target() {
+        let error_probabilities = vec![0.5, 0.01, 0.3, 0.02, 0.9];
+        let selected = fdr_controlled_selection(&error_probabilities, 0.1);
+        let mean: f64 = selected.iter().map(|&i| error_probabilities[i]).sum::<f64>() / selected.len() as f64;
+        assert!(mean <= 0.1 + 1e-9);
+        assert!(!selected.is_empty());
+    }
+
+    #[test]
+    fn test_fdr_controlled_selection_empty_when_all_error_probabilities_too_high() {
+        let error_probabilities = vec![0.9, 0.95, 0.99];
+        let selected = fdr_controlled_selection(&error_probabilities, 0.01);
+        assert!(selected.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+//! DIIS: Direct Inversion in the Iterative Subspace, the convergence
+//! acceleration technique from self-consistent-field electronic structure
+//! solvers, adapted here for any fixed-point iteration expressed as a flat
+//! `Vec<f64>` (so both [`crate::poincare_ops::complete`]'s `SCoord`
+//! iterates and [`crate::charge_ops::minimize_variance`]'s `Array1`
+//! iterates can share one accelerator).
+//!
+//! [`DiisAccelerator`] keeps a bounded history of the last `m` iterates
+//! `p_i` and their residual vectors `r_i`. [`DiisAccelerator::extrapolate`]
+//! builds the `(m+1)x(m+1)` system
+//! `[[B, -1], [-1, 0]] [c; lambda] = [0; -1]` (`B_ij = r_i . r_j`, the `-1`
+//! border enforcing `sum(c) = 1`), solves it by Gaussian elimination with
+//! partial pivoting, and returns the extrapolated point
+//! `p* = sum(c_i * p_i)`. With a single history entry `extrapolate`
+//! returns `p_0` itself (`c = [1]`), so an iteration-0 caller takes its
+//! plain step from the current iterate. A singular system (e.g. duplicate
+//! residuals, or three or more collinear ones) returns `None`; callers
+//! should reset the accelerator and take an ordinary step in that case.
+
+use std::collections::VecDeque;
+
+/// Default bound on retained history (`m` in the module doc comment).
+pub const DEFAULT_DIIS_HISTORY: usize = 8;
+/// Default damping applied to the ordinary step taken from the
+/// extrapolated point, to keep the accelerated step stable near
+/// convergence.
+pub const DEFAULT_DIIS_DAMPING: f64 = 0.8;
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Solves `a * x = b` by Gaussian elimination with partial pivoting.
+/// Returns `None` if `a` is singular (a pivot column has no entry above a
+/// small tolerance).
+fn solve_linear(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let (pivot_row, pivot_val) = (col..n)
+            .map(|row| (row, a[row][col].abs()))
+            .max_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(std::cmp::Ordering::Equal))?;
+        if pivot_val < 1e-10 {
+            return None;
+        }
+        a.swap(col, pivot_row);
+        b.swap(col, pivot_row);
+
+        let pivot = a[col][col];
+        for row in (col + 1)..n {
+            let factor = a[row][col] / pivot;
+            for k in col..n {
+                a[row][k] -= factor * a[col][k];
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let mut sum = b[row];
+        for k in (row + 1)..n {
+            sum -= a[row][k] * x[k];
+        }
+        x[row] = sum / a[row][row];
+    }
+    Some(x)
+}
+
+/// Bounded history of iterates/residuals and the DIIS extrapolation over
+/// them.
+#[derive(Debug, Clone)]
+pub struct DiisAccelerator {
+    max_history: usize,
+    iterates: VecDeque<Vec<f64>>,
+    residuals: VecDeque<Vec<f64>>,
+}
+
+impl DiisAccelerator {
+    pub fn new(max_history: usize) -> Self {
+        Self {
+            max_history: max_history.max(1),
+            iterates: VecDeque::new(),
+            residuals: VecDeque::new(),
+        }
+    }
+
+    /// Records one `(iterate, residual)` pair, dropping the oldest pair
+    /// first if the history is already at capacity.
+    pub fn push(&mut self, iterate: Vec<f64>, residual: Vec<f64>) {
+        if self.iterates.len() == self.max_history {
+            self.iterates.pop_front();
+            self.residuals.pop_front();
+        }
+        self.iterates.push_back(iterate);
+        self.residuals.push_back(residual);
+    }
+
+    pub fn len(&self) -> usize {
+        self.iterates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iterates.is_empty()
+    }
+
+    /// Clears the retained history, e.g. after a singular extrapolation or
+    /// a detected regime change.
+    pub fn reset(&mut self) {
+        self.iterates.clear();
+        self.residuals.clear();
+    }
+
+    /// Extrapolates `p* = sum(c_i * p_i)` from the retained history.
+    /// `None` if the history is empty or the DIIS linear system is
+    /// singular.
+    pub fn extrapolate(&self) -> Option<Vec<f64>> {
+        let m = self.iterates.len();
+        if m == 0 {
+            return None;
+        }
+        if m == 1 {
+            return Some(self.iterates[0].clone());
+        }
+
+        let mut a = vec![vec![0.0; m + 1]; m + 1];
+        let mut b = vec![0.0; m + 1];
+        for i in 0..m {
+            for j in 0..m {
+                a[i][j] = dot(&self.residuals[i], &self.residuals[j]);
+            }
+            a[i][m] = -1.0;
+            a[m][i] = -1.0;
+        }
+        b[m] = -1.0;
+
+        let x = solve_linear(a, b)?;
+        let dim = self.iterates[0].len();
+        let mut p_star = vec![0.0; dim];
+        for (coeff, iterate) in x[..m].iter().zip(self.iterates.iter()) {
+            for (p, &v) in p_star.iter_mut().zip(iterate.iter()) {
+                *p += coeff * v;
+            }
+        }
+        Some(p_star)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate_empty_history_returns_none() {
+        let diis = DiisAccelerator::new(DEFAULT_DIIS_HISTORY);
+        assert!(diis.extrapolate().is_none());
+    }
+
+    #[test]
+    fn test_extrapolate_single_entry_returns_that_iterate() {
+        let mut diis = DiisAccelerator::new(DEFAULT_DIIS_HISTORY);
+        diis.push(vec![1.0, 2.0], vec![0.5, 0.5]);
+        assert_eq!(diis.extrapolate(), Some(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_extrapolate_balances_opposite_residuals_to_midpoint() {
+        let mut diis = DiisAccelerator::new(DEFAULT_DIIS_HISTORY);
+        diis.push(vec![0.0, 0.0], vec![1.0, 0.0]);
+        diis.push(vec![2.0, 2.0], vec![-1.0, 0.0]);
+
+        let p_star = diis.extrapolate().expect("well-conditioned system");
+        assert!((p_star[0] - 1.0).abs() < 1e-9, "p_star = {p_star:?}");
+        assert!((p_star[1] - 1.0).abs() < 1e-9, "p_star = {p_star:?}");
+    }
+
+    #[test]
+    fn test_extrapolate_singular_system_returns_none() {
+        let mut diis = DiisAccelerator::new(DEFAULT_DIIS_HISTORY);
+        diis.push(vec![0.0, 0.0], vec![1.0, 0.0]);
+        diis.push(vec![1.0, 1.0], vec![1.0, 0.0]); // identical residual -> singular B
+
+        assert!(diis.extrapolate().is_none());
+    }
+
+    #[test]
+    fn test_push_drops_oldest_pair_once_history_is_full() {
+        let mut diis = DiisAccelerator::new(2);
+        diis.push(vec![0.0, 0.0], vec![5.0, 5.0]); // pushed, then evicted below
+        diis.push(vec![0.0, 0.0], vec![1.0, 0.0]);
+        diis.push(vec![2.0, 2.0], vec![-1.0, 0.0]);
+
+        assert_eq!(diis.len(), 2);
+        let p_star = diis.extrapolate().expect("well-conditioned once the stale pair is evicted");
+        assert!((p_star[0] - 1.0).abs() < 1e-9, "p_star = {p_star:?}");
+        assert!((p_star[1] - 1.0).abs() < 1e-9, "p_star = {p_star:?}");
+    }
+
+    #[test]
+    fn test_reset_clears_history() {
+        let mut diis = DiisAccelerator::new(DEFAULT_DIIS_HISTORY);
+        diis.push(vec![1.0], vec![1.0]);
+        diis.reset();
+        assert!(diis.is_empty());
+        assert!(diis.extrapolate().is_none());
+    }
+}
@@ -3,9 +3,10 @@
 //! Implements Kuramoto model for oscillator synchronization:
 //! dφᵢ/dt = ωᵢ + (K/N) Σⱼ sin(φⱼ - φᵢ)
 
-use ndarray::Array1;
+use ndarray::{Array1, Array2};
 use num_complex::Complex64;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rand_distr::{Distribution, Normal};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
@@ -20,15 +21,36 @@ pub struct KuramotoState {
     pub natural_frequencies: Array1<f64>,
     /// Global coupling strength K
     pub coupling_strength: f64,
+    /// Optional N×N weighted adjacency matrix `Aᵢⱼ` for non-all-to-all
+    /// topologies (Erdős–Rényi, small-world, empirical connectomes, ...).
+    /// `None` falls back to uniform all-to-all coupling.
+    pub coupling_matrix: Option<Array2<f64>>,
 }
 
 impl KuramotoState {
-    /// Create new Kuramoto state.
+    /// Create new Kuramoto state with uniform all-to-all coupling.
     pub fn new(phases: Array1<f64>, natural_frequencies: Array1<f64>, coupling_strength: f64) -> Self {
         Self {
             phases,
             natural_frequencies,
             coupling_strength,
+            coupling_matrix: None,
+        }
+    }
+
+    /// Create new Kuramoto state coupled through an explicit adjacency
+    /// matrix instead of uniform all-to-all coupling.
+    pub fn new_with_matrix(
+        phases: Array1<f64>,
+        natural_frequencies: Array1<f64>,
+        coupling_strength: f64,
+        coupling_matrix: Array2<f64>,
+    ) -> Self {
+        Self {
+            phases,
+            natural_frequencies,
+            coupling_strength,
+            coupling_matrix: Some(coupling_matrix),
         }
     }
 
@@ -39,20 +61,76 @@ impl KuramotoState {
         frequency_std: f64,
         coupling: f64,
     ) -> Self {
-        let mut rng = rand::thread_rng();
+        Self::random_with_rng(
+            &mut rand::thread_rng(),
+            n_oscillators,
+            mean_frequency,
+            frequency_std,
+            coupling,
+            None,
+        )
+    }
+
+    /// Create a random Kuramoto state from a `u64` seed, for reproducible
+    /// validation runs: the same seed always produces the same phases and
+    /// natural frequencies.
+    pub fn random_seeded(
+        n_oscillators: usize,
+        mean_frequency: f64,
+        frequency_std: f64,
+        coupling: f64,
+        seed: u64,
+    ) -> Self {
+        Self::random_with_rng(
+            &mut StdRng::seed_from_u64(seed),
+            n_oscillators,
+            mean_frequency,
+            frequency_std,
+            coupling,
+            None,
+        )
+    }
+
+    /// Create a random Kuramoto state driven by an explicit adjacency
+    /// matrix rather than uniform all-to-all coupling.
+    pub fn random_with_matrix(
+        mean_frequency: f64,
+        frequency_std: f64,
+        coupling: f64,
+        coupling_matrix: Array2<f64>,
+    ) -> Self {
+        let n_oscillators = coupling_matrix.nrows();
+        Self::random_with_rng(
+            &mut rand::thread_rng(),
+            n_oscillators,
+            mean_frequency,
+            frequency_std,
+            coupling,
+            Some(coupling_matrix),
+        )
+    }
 
+    fn random_with_rng(
+        rng: &mut impl Rng,
+        n_oscillators: usize,
+        mean_frequency: f64,
+        frequency_std: f64,
+        coupling: f64,
+        coupling_matrix: Option<Array2<f64>>,
+    ) -> Self {
         let phases =
             Array1::from_iter((0..n_oscillators).map(|_| rng.gen::<f64>() * 2.0 * PI));
 
         let normal = Normal::new(mean_frequency * 2.0 * PI, frequency_std * 2.0 * PI)
             .unwrap_or_else(|_| Normal::new(mean_frequency * 2.0 * PI, 1.0).unwrap());
         let frequencies =
-            Array1::from_iter((0..n_oscillators).map(|_| normal.sample(&mut rng)));
+            Array1::from_iter((0..n_oscillators).map(|_| normal.sample(rng)));
 
         Self {
             phases,
             natural_frequencies: frequencies,
             coupling_strength: coupling,
+            coupling_matrix,
         }
     }
 
@@ -62,31 +140,121 @@ impl KuramotoState {
     }
 }
 
-/// KURAMOTO operator: Evolve Kuramoto oscillator network.
-///
-/// Implements: dφᵢ/dt = ωᵢ + (K/N) Σⱼ sin(φⱼ - φᵢ)
-pub fn kuramoto(state: &KuramotoState, dt: f64) -> KuramotoState {
-    let n = state.n_oscillators();
+/// All-to-all mean-field coupling term: `(K/N)(cos(φᵢ)·S - sin(φᵢ)·C)`.
+fn all_to_all_coupling(phases: &Array1<f64>, k: f64) -> Array1<f64> {
+    let n = phases.len();
+    let sin_phases = phases.mapv(f64::sin);
+    let cos_phases = phases.mapv(f64::cos);
+    let s = sin_phases.sum();
+    let c = cos_phases.sum();
+    (&cos_phases * s - &sin_phases * c) * (k / n as f64)
+}
+
+/// Adjacency-weighted coupling term: `(K/kᵢ) Σⱼ Aᵢⱼ sin(φⱼ - φᵢ)`, where
+/// `kᵢ` is node `i`'s degree (its row sum in `matrix`). Nodes with zero
+/// degree receive no coupling contribution.
+fn matrix_coupling(phases: &Array1<f64>, k: f64, matrix: &Array2<f64>) -> Array1<f64> {
+    let n = phases.len();
+    Array1::from_iter((0..n).map(|i| {
+        let degree: f64 = matrix.row(i).sum();
+        if degree == 0.0 {
+            return 0.0;
+        }
+        let weighted_sum: f64 = (0..n)
+            .map(|j| matrix[[i, j]] * (phases[j] - phases[i]).sin())
+            .sum();
+        (k / degree) * weighted_sum
+    }))
+}
+
+/// Instantaneous `dφ/dt` for every oscillator in `state`, given a phase
+/// vector that may differ from `state.phases` (used by [`RungeKutta4`] to
+/// evaluate the derivative at intermediate stages while natural
+/// frequencies and coupling topology stay fixed).
+fn kuramoto_deriv(state: &KuramotoState, phases: &Array1<f64>) -> Array1<f64> {
     let k = state.coupling_strength;
+    let coupling = match &state.coupling_matrix {
+        Some(matrix) => matrix_coupling(phases, k, matrix),
+        None => all_to_all_coupling(phases, k),
+    };
+    &state.natural_frequencies + &coupling
+}
 
-    // Compute coupling term (vectorized)
-    let mut d_phases = state.natural_frequencies.clone();
+/// A numerical integration scheme advancing a phase vector by one step of
+/// size `dt` given its derivative function.
+pub trait Integrator {
+    /// Advance `phases` by `dt` using `deriv` to evaluate `dφ/dt` at
+    /// whatever intermediate phase vectors the scheme needs. Implementors
+    /// must wrap the final result into `[0, 2*pi)` themselves.
+    fn step(&self, phases: &Array1<f64>, dt: f64, deriv: &dyn Fn(&Array1<f64>) -> Array1<f64>) -> Array1<f64>;
+}
 
-    for i in 0..n {
-        let mut coupling_sum = 0.0;
-        for j in 0..n {
-            coupling_sum += (state.phases[j] - state.phases[i]).sin();
-        }
-        d_phases[i] += (k / n as f64) * coupling_sum;
+/// Explicit forward-Euler integrator: `φ(t+dt) = φ(t) + dt·dφ/dt(t)`.
+/// Simple and cheap, but accumulates phase error and is unstable at
+/// larger step sizes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Euler;
+
+impl Integrator for Euler {
+    fn step(&self, phases: &Array1<f64>, dt: f64, deriv: &dyn Fn(&Array1<f64>) -> Array1<f64>) -> Array1<f64> {
+        (phases + &(deriv(phases) * dt)).mapv(|x| x.rem_euclid(2.0 * PI))
     }
+}
+
+/// Classic 4th-order Runge-Kutta integrator. Evaluates the derivative at
+/// four stages (`k1` at `t`, `k2`/`k3` at `t+dt/2` using intermediate
+/// phases, `k4` at `t+dt`) and combines them as `(k1+2k2+2k3+k4)/6`,
+/// wrapping into `[0, 2*pi)` only after the final combined update so
+/// intermediate stages don't introduce wrapping artifacts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RungeKutta4;
+
+impl Integrator for RungeKutta4 {
+    fn step(&self, phases: &Array1<f64>, dt: f64, deriv: &dyn Fn(&Array1<f64>) -> Array1<f64>) -> Array1<f64> {
+        let k1 = deriv(phases);
+        let k2 = deriv(&(phases + &(&k1 * (dt / 2.0))));
+        let k3 = deriv(&(phases + &(&k2 * (dt / 2.0))));
+        let k4 = deriv(&(phases + &(&k3 * dt)));
+
+        let combined = (&k1 + &(&k2 * 2.0) + &(&k3 * 2.0) + &k4) / 6.0;
+        (phases + &(&combined * dt)).mapv(|x| x.rem_euclid(2.0 * PI))
+    }
+}
 
-    // Update phases
-    let new_phases = (&state.phases + &(&d_phases * dt)).mapv(|x| x.rem_euclid(2.0 * PI));
+/// KURAMOTO operator: Evolve Kuramoto oscillator network by one
+/// forward-Euler step. Implements: dφᵢ/dt = ωᵢ + (K/N) Σⱼ sin(φⱼ - φᵢ)
+///
+/// The naive all-to-all form sums `sin(φⱼ - φᵢ)` over all `j` for every
+/// `i`, which is O(N²) per step. Expanding via the angle-difference
+/// identity, `sin(φⱼ - φᵢ) = sin(φⱼ)cos(φᵢ) - cos(φⱼ)sin(φᵢ)`, so
+/// `Σⱼ sin(φⱼ - φᵢ) = cos(φᵢ)·S - sin(φᵢ)·C` where `S = Σⱼ sin(φⱼ)` and
+/// `C = Σⱼ cos(φⱼ)` are each computed once per step. This is algebraically
+/// identical to the nested sum, just O(N) instead of O(N²).
+///
+/// When `state.coupling_matrix` is set, this mean-field shortcut doesn't
+/// apply (coupling is no longer uniform across pairs) and the model falls
+/// back to `dφᵢ/dt = ωᵢ + (K/kᵢ) Σⱼ Aᵢⱼ sin(φⱼ - φᵢ)`, normalized by each
+/// node's degree `kᵢ` (its row sum in the adjacency matrix) so coupling
+/// strength doesn't scale with how connected a node happens to be.
+///
+/// Always takes an explicit Euler step, for callers that don't need to
+/// choose an [`Integrator`]; [`kuramoto_with_integrator`] exposes RK4 (or
+/// any other scheme) for the same dynamics.
+pub fn kuramoto(state: &KuramotoState, dt: f64) -> KuramotoState {
+    kuramoto_with_integrator(state, dt, &Euler)
+}
+
+/// Like [`kuramoto`], but advances the phase vector with a caller-chosen
+/// [`Integrator`] (e.g. [`RungeKutta4`] for better accuracy at larger
+/// `dt`) instead of always taking a forward-Euler step.
+pub fn kuramoto_with_integrator(state: &KuramotoState, dt: f64, integrator: &dyn Integrator) -> KuramotoState {
+    let new_phases = integrator.step(&state.phases, dt, &|phases| kuramoto_deriv(state, phases));
 
     KuramotoState {
         phases: new_phases,
         natural_frequencies: state.natural_frequencies.clone(),
-        coupling_strength: k,
+        coupling_strength: state.coupling_strength,
+        coupling_matrix: state.coupling_matrix.clone(),
     }
 }
 
@@ -103,10 +271,45 @@ pub fn kuramoto_with_drug(
         phases: state.phases.clone(),
         natural_frequencies: state.natural_frequencies.clone(),
         coupling_strength: effective_k.max(0.0),
+        coupling_matrix: state.coupling_matrix.clone(),
     };
     kuramoto(&modified_state, dt)
 }
 
+/// Noisy Kuramoto model via Euler-Maruyama: `dφᵢ = [ωᵢ + (K/N)Σⱼ sin(φⱼ-φᵢ)]
+/// dt + σ dWᵢ`. The deterministic drift term is the same `kuramoto_deriv`
+/// used by the plain (and matrix-coupled) KURAMOTO operator; each
+/// oscillator additionally receives an independent Gaussian increment
+/// sampled from `Normal(0, noise_strength * sqrt(dt))`, modeling
+/// thermal/biological jitter on top of the phase-coupling dynamics.
+///
+/// Takes the RNG by mutable reference (the same convention as
+/// `KuramotoState::random_with_rng`) rather than a seed of its own, so a
+/// caller can thread a single `StdRng` through repeated calls for a fully
+/// reproducible trajectory; see [`simulate_kuramoto_stochastic`] for a
+/// ready-made seeded driver.
+pub fn kuramoto_stochastic(
+    state: &KuramotoState,
+    dt: f64,
+    noise_strength: f64,
+    rng: &mut impl Rng,
+) -> KuramotoState {
+    let drift = kuramoto_deriv(state, &state.phases);
+    let normal = Normal::new(0.0, noise_strength * dt.sqrt())
+        .unwrap_or_else(|_| Normal::new(0.0, 1e-12).unwrap());
+
+    let new_phases = (&state.phases + &(&drift * dt))
+        .mapv(|phi| phi + normal.sample(rng))
+        .mapv(|phi| phi.rem_euclid(2.0 * PI));
+
+    KuramotoState {
+        phases: new_phases,
+        natural_frequencies: state.natural_frequencies.clone(),
+        coupling_strength: state.coupling_strength,
+        coupling_matrix: state.coupling_matrix.clone(),
+    }
+}
+
 /// PHASE_LOCK operator: Compute Kuramoto order parameter.
 ///
 /// Returns (R, Psi) - magnitude and mean phase.
@@ -127,6 +330,24 @@ pub fn coherence(phases: &Array1<f64>) -> f64 {
     phase_lock(phases).0
 }
 
+/// Per-node local order parameter `Rᵢ`: coherence computed only over node
+/// `i`'s neighbors (the nodes `j` with `Aᵢⱼ != 0` in `matrix`), rather
+/// than `phase_lock`'s global average over all nodes. Meaningful only for
+/// matrix-coupled (non all-to-all) topologies, where different
+/// neighborhoods can synchronize at different rates. A node with no
+/// neighbors has an undefined local neighborhood and returns 0.
+pub fn local_order_parameter(phases: &Array1<f64>, matrix: &Array2<f64>) -> Array1<f64> {
+    let n = phases.len();
+    Array1::from_iter((0..n).map(|i| {
+        let neighbors: Vec<usize> = (0..n).filter(|&j| matrix[[i, j]] != 0.0).collect();
+        if neighbors.is_empty() {
+            return 0.0;
+        }
+        let neighbor_phases = Array1::from_iter(neighbors.iter().map(|&j| phases[j]));
+        phase_lock(&neighbor_phases).0
+    }))
+}
+
 /// CASCADE operator: Multi-scale frequency cascade.
 pub fn cascade(input_frequency: f64, gear_ratios: Option<&[f64]>) -> Vec<f64> {
     let ratios = gear_ratios.unwrap_or(&BIOLOGICAL_GEAR_RATIOS);
@@ -144,11 +365,14 @@ pub fn variance(values: &Array1<f64>) -> f64 {
     values.mapv(|x| (x - mean).powi(2)).mean().unwrap_or(0.0)
 }
 
-/// Simulate Kuramoto dynamics over time.
+/// Simulate Kuramoto dynamics over time, stepping with the given
+/// [`Integrator`] (e.g. `&Euler` for the original forward-Euler behavior,
+/// or `&RungeKutta4` for better accuracy at larger `dt`).
 pub fn simulate_kuramoto(
     initial_state: &KuramotoState,
     duration: f64,
     dt: f64,
+    integrator: &dyn Integrator,
 ) -> (Array1<f64>, Array1<f64>, Array1<f64>) {
     let n_steps = (duration / dt) as usize;
     let mut times = Array1::zeros(n_steps);
@@ -162,7 +386,40 @@ pub fn simulate_kuramoto(
         let (r, psi) = phase_lock(&state.phases);
         order_params[i] = r;
         mean_phases[i] = psi;
-        state = kuramoto(&state, dt);
+        state = kuramoto_with_integrator(&state, dt, integrator);
+    }
+
+    (times, order_params, mean_phases)
+}
+
+/// Simulate the noisy Kuramoto model ([`kuramoto_stochastic`]) over time.
+/// `seed` selects the RNG driving the whole trajectory: `Some(seed)` for a
+/// fully reproducible run (the same seed always produces the same noise
+/// sequence), `None` to seed from OS entropy for a genuinely random run.
+pub fn simulate_kuramoto_stochastic(
+    initial_state: &KuramotoState,
+    duration: f64,
+    dt: f64,
+    noise_strength: f64,
+    seed: Option<u64>,
+) -> (Array1<f64>, Array1<f64>, Array1<f64>) {
+    let n_steps = (duration / dt) as usize;
+    let mut times = Array1::zeros(n_steps);
+    let mut order_params = Array1::zeros(n_steps);
+    let mut mean_phases = Array1::zeros(n_steps);
+
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut state = initial_state.clone();
+
+    for i in 0..n_steps {
+        times[i] = i as f64 * dt;
+        let (r, psi) = phase_lock(&state.phases);
+        order_params[i] = r;
+        mean_phases[i] = psi;
+        state = kuramoto_stochastic(&state, dt, noise_strength, &mut rng);
     }
 
     (times, order_params, mean_phases)
@@ -174,6 +431,75 @@ pub fn critical_coupling(frequency_std: f64, _n_oscillators: usize) -> f64 {
     2.0 * frequency_std / PI
 }
 
+/// Result of [`sync_transition_sweep`]: the time-averaged synchronization
+/// curve `R(K)` plus the empirical and analytic estimates of the critical
+/// coupling where the transition occurs.
+#[derive(Debug, Clone)]
+pub struct SyncTransitionResult {
+    /// Coupling strengths `K` that were swept, same order as `order_parameters`.
+    pub couplings: Array1<f64>,
+    /// Time-averaged order parameter `R(K)` at each swept coupling, with
+    /// the initial transient discarded.
+    pub order_parameters: Array1<f64>,
+    /// The swept `K` at which `R(K)` first rises above `0.5 * max(R)`.
+    pub empirical_critical_coupling: f64,
+    /// `critical_coupling` evaluated on the natural-frequency spread of
+    /// the swept network, for comparison against `empirical_critical_coupling`.
+    pub analytic_critical_coupling: f64,
+}
+
+/// Sweep the Kuramoto coupling strength `K` over `coupling_values`,
+/// running [`simulate_kuramoto`] to (approximate) steady state at each
+/// value and recording the time-averaged order parameter `R(K)` over the
+/// final `transient_fraction` of the run (discarding the transient before
+/// the network settles). This turns the scalar `critical_coupling`
+/// estimate into a full empirical transition curve, letting callers
+/// validate that a given frequency distribution's measured transition
+/// matches the analytic `K_c ≈ 2σ/π` prediction.
+///
+/// The empirical critical coupling is taken as the first swept `K` whose
+/// `R(K)` rises above half of the curve's maximum — a standard onset
+/// criterion for a sigmoidal synchronization transition.
+pub fn sync_transition_sweep(
+    initial_state: &KuramotoState,
+    coupling_values: &[f64],
+    duration: f64,
+    dt: f64,
+    transient_fraction: f64,
+) -> SyncTransitionResult {
+    let order_parameters = Array1::from_iter(coupling_values.iter().map(|&k| {
+        let mut state = initial_state.clone();
+        state.coupling_strength = k;
+
+        let (_, order_params, _) = simulate_kuramoto(&state, duration, dt, &Euler);
+        let n_steps = order_params.len();
+        let start = (n_steps as f64 * (1.0 - transient_fraction)).round() as usize;
+        let start = start.min(n_steps.saturating_sub(1));
+
+        order_params.iter().skip(start).sum::<f64>() / (n_steps - start) as f64
+    }));
+
+    let r_max = order_parameters.iter().cloned().fold(f64::MIN, f64::max);
+    let threshold = 0.5 * r_max;
+    let empirical_critical_coupling = coupling_values
+        .iter()
+        .zip(order_parameters.iter())
+        .find(|(_, &r)| r >= threshold)
+        .map(|(&k, _)| k)
+        .unwrap_or(f64::NAN);
+
+    let frequency_std = variance(&initial_state.natural_frequencies).sqrt();
+    let analytic_critical_coupling =
+        critical_coupling(frequency_std, initial_state.n_oscillators());
+
+    SyncTransitionResult {
+        couplings: Array1::from_vec(coupling_values.to_vec()),
+        order_parameters,
+        empirical_critical_coupling,
+        analytic_critical_coupling,
+    }
+}
+
 /// Phase velocity for traveling waves.
 pub fn phase_velocity(coupling_strength: f64, diffusion_coeff: f64) -> f64 {
     (coupling_strength * diffusion_coeff).sqrt()
@@ -227,4 +553,206 @@ mod tests {
         let evolved = kuramoto(&state, 0.01);
         assert_eq!(evolved.n_oscillators(), state.n_oscillators());
     }
+
+    #[test]
+    fn test_kuramoto_matches_naive_pairwise_sum() {
+        // The O(N) mean-field coupling must match the original nested
+        // O(N^2) sum over sin(phi_j - phi_i) up to round-off.
+        let state = KuramotoState::random_seeded(25, 1.0, 0.3, 1.5, 42);
+        let n = state.n_oscillators();
+        let dt = 0.01;
+
+        let mut naive_d_phases = state.natural_frequencies.clone();
+        for i in 0..n {
+            let mut coupling_sum = 0.0;
+            for j in 0..n {
+                coupling_sum += (state.phases[j] - state.phases[i]).sin();
+            }
+            naive_d_phases[i] += (state.coupling_strength / n as f64) * coupling_sum;
+        }
+        let naive_phases = (&state.phases + &(&naive_d_phases * dt)).mapv(|x| x.rem_euclid(2.0 * PI));
+
+        let evolved = kuramoto(&state, dt);
+        for (a, b) in evolved.phases.iter().zip(naive_phases.iter()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_matrix_coupling_with_full_adjacency_matches_all_to_all() {
+        // A fully-connected, unit-weighted adjacency matrix (degree N-1
+        // per node, since self-coupling is excluded) should reproduce the
+        // same dynamics as the all-to-all fallback once normalized by
+        // (N-1) instead of N.
+        let n = 12;
+        let mut full = Array2::from_elem((n, n), 1.0);
+        for i in 0..n {
+            full[[i, i]] = 0.0;
+        }
+
+        let base = KuramotoState::random_seeded(n, 1.0, 0.3, 1.0, 7);
+        let all_to_all = KuramotoState::new(
+            base.phases.clone(),
+            base.natural_frequencies.clone(),
+            base.coupling_strength * n as f64 / (n - 1) as f64,
+        );
+        let matrix_state = KuramotoState::new_with_matrix(
+            base.phases.clone(),
+            base.natural_frequencies.clone(),
+            base.coupling_strength,
+            full,
+        );
+
+        let evolved_a = kuramoto(&all_to_all, 0.01);
+        let evolved_b = kuramoto(&matrix_state, 0.01);
+        for (a, b) in evolved_a.phases.iter().zip(evolved_b.phases.iter()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_matrix_coupling_isolated_node_keeps_natural_frequency() {
+        // A node with no edges (zero row) should evolve at its bare
+        // natural frequency, receiving no coupling contribution.
+        let n = 4;
+        let matrix = Array2::from_elem((n, n), 0.0);
+        let phases = Array1::from_vec(vec![0.0, 1.0, 2.0, 3.0]);
+        let frequencies = Array1::from_vec(vec![0.5, 0.5, 0.5, 0.5]);
+        let state = KuramotoState::new_with_matrix(phases.clone(), frequencies.clone(), 2.0, matrix);
+
+        let evolved = kuramoto(&state, 0.1);
+        for i in 0..n {
+            let expected = (phases[i] + frequencies[i] * 0.1).rem_euclid(2.0 * PI);
+            assert!((evolved.phases[i] - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_local_order_parameter_matches_global_for_fully_connected_neighborhood() {
+        let n = 16;
+        let mut full = Array2::from_elem((n, n), 1.0);
+        for i in 0..n {
+            full[[i, i]] = 0.0;
+        }
+        let phases = Array1::from_elem(n, 0.75);
+        let local = local_order_parameter(&phases, &full);
+        let global = coherence(&phases);
+        for &r in local.iter() {
+            assert!((r - global).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_local_order_parameter_zero_for_isolated_node() {
+        let n = 5;
+        let matrix = Array2::from_elem((n, n), 0.0);
+        let phases = Array1::from_vec(vec![0.1, 0.2, 0.3, 0.4, 0.5]);
+        let local = local_order_parameter(&phases, &matrix);
+        assert_eq!(local[0], 0.0);
+    }
+
+    #[test]
+    fn test_kuramoto_default_matches_euler_integrator() {
+        let state = KuramotoState::random_seeded(15, 1.0, 0.2, 1.0, 11);
+        let via_default = kuramoto(&state, 0.01);
+        let via_euler = kuramoto_with_integrator(&state, 0.01, &Euler);
+        for (a, b) in via_default.phases.iter().zip(via_euler.phases.iter()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rk4_matches_euler_for_a_single_free_oscillator() {
+        // With zero coupling, dphi/dt is constant (just the natural
+        // frequency), so RK4 and Euler must agree exactly regardless of
+        // step size: all four RK4 stages evaluate to the same constant.
+        let phases = Array1::from_vec(vec![0.3]);
+        let frequencies = Array1::from_vec(vec![2.0]);
+        let state = KuramotoState::new(phases, frequencies, 0.0);
+
+        let euler_result = kuramoto_with_integrator(&state, 0.5, &Euler);
+        let rk4_result = kuramoto_with_integrator(&state, 0.5, &RungeKutta4);
+        assert!((euler_result.phases[0] - rk4_result.phases[0]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rk4_conserves_order_parameter_bounds() {
+        let state = KuramotoState::random_seeded(20, 1.0, 0.3, 2.0, 3);
+        let evolved = kuramoto_with_integrator(&state, 0.01, &RungeKutta4);
+        let (r, _) = phase_lock(&evolved.phases);
+        assert!((0.0..=1.0).contains(&r));
+    }
+
+    #[test]
+    fn test_simulate_kuramoto_with_rk4_synchronizes_strongly_coupled_network() {
+        let state = KuramotoState::random_seeded(30, 1.0, 0.1, 5.0, 99);
+        let (_, order_params, _) = simulate_kuramoto(&state, 20.0, 0.01, &RungeKutta4);
+        assert!(order_params[order_params.len() - 1] > 0.9);
+    }
+
+    #[test]
+    fn test_kuramoto_stochastic_zero_noise_matches_deterministic_kuramoto() {
+        let state = KuramotoState::random_seeded(10, 1.0, 0.2, 1.5, 5);
+        let mut rng = StdRng::seed_from_u64(0);
+        let noisy = kuramoto_stochastic(&state, 0.01, 0.0, &mut rng);
+        let plain = kuramoto(&state, 0.01);
+        for (a, b) in noisy.phases.iter().zip(plain.phases.iter()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_kuramoto_stochastic_same_seed_is_reproducible() {
+        let state = KuramotoState::random_seeded(10, 1.0, 0.2, 1.5, 5);
+        let mut rng_a = StdRng::seed_from_u64(123);
+        let mut rng_b = StdRng::seed_from_u64(123);
+
+        let a = kuramoto_stochastic(&state, 0.01, 0.5, &mut rng_a);
+        let b = kuramoto_stochastic(&state, 0.01, 0.5, &mut rng_b);
+        for (x, y) in a.phases.iter().zip(b.phases.iter()) {
+            assert!((x - y).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_simulate_kuramoto_stochastic_same_seed_reproducible() {
+        let state = KuramotoState::random_seeded(10, 1.0, 0.2, 1.5, 5);
+        let (_, order_a, _) = simulate_kuramoto_stochastic(&state, 1.0, 0.05, 0.3, Some(42));
+        let (_, order_b, _) = simulate_kuramoto_stochastic(&state, 1.0, 0.05, 0.3, Some(42));
+        for (a, b) in order_a.iter().zip(order_b.iter()) {
+            assert!((a - b).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_sync_transition_sweep_order_parameter_rises_with_coupling() {
+        let state = KuramotoState::random_seeded(40, 1.0, 0.3, 0.0, 17);
+        let couplings: Vec<f64> = vec![0.0, 0.5, 1.0, 2.0, 4.0, 8.0];
+
+        let result = sync_transition_sweep(&state, &couplings, 20.0, 0.02, 0.5);
+        assert_eq!(result.order_parameters.len(), couplings.len());
+
+        // Weak coupling should leave the network far less synchronized
+        // than strong coupling.
+        let first = result.order_parameters[0];
+        let last = result.order_parameters[result.order_parameters.len() - 1];
+        assert!(last > first);
+    }
+
+    #[test]
+    fn test_sync_transition_sweep_empirical_near_analytic_critical_coupling() {
+        let state = KuramotoState::random_seeded(60, 1.0, 0.2, 0.0, 23);
+        let analytic_kc = critical_coupling(
+            variance(&state.natural_frequencies).sqrt(),
+            state.n_oscillators(),
+        );
+
+        let couplings: Vec<f64> = (0..12).map(|i| i as f64 * analytic_kc / 3.0).collect();
+        let result = sync_transition_sweep(&state, &couplings, 30.0, 0.02, 0.5);
+
+        assert!((result.analytic_critical_coupling - analytic_kc).abs() < 1e-12);
+        // The empirical onset should land within the same order of
+        // magnitude as the analytic prediction for this frequency spread.
+        assert!(result.empirical_critical_coupling < analytic_kc * 4.0);
+    }
 }
@@ -0,0 +1,320 @@
+//! Genomic Calling: Bayesian posterior probabilities for candidate genomic
+//! events (palindromic sites, motif occurrences, strand-specific
+//! features), replacing boolean pattern detection with calibrated calls.
+//!
+//! Each [`CandidateSite`] is scored against the raw sequence to get a
+//! local-composition match score, which becomes a Bayes factor (likelihood
+//! ratio) against [`CallingSettings::prior`]. The region's S-entropy
+//! coordinate discounts that evidence by its evolution-entropy component
+//! (`se`): a site in a high-uncertainty region contributes less evidence,
+//! so a call under `se = 1` collapses back to the bare prior. The
+//! resulting posterior odds give both a probability and a log-odds
+//! (evidence) score, plus a confidence interval derived from how much
+//! sequence the match score was computed over. [`call_candidate_sites`]
+//! emits only the calls whose posterior probability clears
+//! `CallingSettings::probability_threshold`, ranked by descending
+//! log-odds.
+
+use vb_core::types::SCoord;
+
+/// The kind of genomic event a [`CandidateSite`] is being tested for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CandidateEventKind {
+    Palindrome,
+    /// Matches against a fixed consensus `motif` sequence.
+    Motif(String),
+    StrandSpecific,
+}
+
+/// A candidate region to call, identified by its half-open `[start, end)`
+/// offset into the sequence being analyzed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateSite {
+    pub start: usize,
+    pub end: usize,
+    pub kind: CandidateEventKind,
+}
+
+/// Configurable prior/threshold for the calling subsystem (stands in for
+/// `OptimizationSettings`' prior/threshold fields, which have no analog in
+/// this crate).
+#[derive(Debug, Clone, Copy)]
+pub struct CallingSettings {
+    /// Prior probability of a true event at an arbitrary candidate site,
+    /// before any sequence evidence is considered.
+    pub prior: f64,
+    /// Minimum posterior probability for a call to be emitted.
+    pub probability_threshold: f64,
+}
+
+impl Default for CallingSettings {
+    fn default() -> Self {
+        Self {
+            prior: 0.1,
+            probability_threshold: 0.5,
+        }
+    }
+}
+
+/// A called event: its posterior probability, the evidence (log-odds)
+/// that produced it, a confidence interval around the probability, and a
+/// `[0, 1]` significance score for ranking against other biological
+/// patterns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PosteriorCall {
+    pub site: CandidateSite,
+    pub probability: f64,
+    pub log_odds: f64,
+    pub confidence_interval: (f64, f64),
+    pub significance_score: f64,
+}
+
+fn complement(base: u8) -> u8 {
+    match base.to_ascii_uppercase() {
+        b'A' => b'T',
+        b'T' => b'A',
+        b'C' => b'G',
+        b'G' => b'C',
+        other => other,
+    }
+}
+
+/// Fraction of bases in `window` that match their mirrored complement,
+/// i.e. how close `window` is to a perfect palindrome.
+fn palindrome_match_score(window: &[u8]) -> f64 {
+    if window.is_empty() {
+        return 0.0;
+    }
+    let n = window.len();
+    let matches = (0..n / 2)
+        .filter(|&i| complement(window[i]) == window[n - 1 - i].to_ascii_uppercase())
+        .count();
+    let pairs = n / 2;
+    if pairs == 0 {
+        1.0
+    } else {
+        matches as f64 / pairs as f64
+    }
+}
+
+/// Position-wise match fraction between `window` and a fixed-length
+/// consensus `motif` (case-insensitive), aligned at `window`'s start.
+fn motif_match_score(window: &[u8], motif: &str) -> f64 {
+    let motif = motif.as_bytes();
+    let n = window.len().min(motif.len());
+    if n == 0 {
+        return 0.0;
+    }
+    let matches = (0..n)
+        .filter(|&i| window[i].to_ascii_uppercase() == motif[i].to_ascii_uppercase())
+        .count();
+    matches as f64 / n as f64
+}
+
+/// GC skew `|G - C| / (G + C)` over `window`, a simple strand-asymmetry
+/// indicator: `0.0` for balanced composition, `1.0` for fully skewed.
+fn strand_asymmetry_score(window: &[u8]) -> f64 {
+    let g = window.iter().filter(|&&b| b.to_ascii_uppercase() == b'G').count() as f64;
+    let c = window.iter().filter(|&&b| b.to_ascii_uppercase() == b'C').count() as f64;
+    if g + c <= 0.0 {
+        return 0.0;
+    }
+    ((g - c) / (g + c)).abs()
+}
+
+fn match_score(sequence: &[u8], site: &CandidateSite) -> f64 {
+    let start = site.start.min(sequence.len());
+    let end = site.end.min(sequence.len());
+    if start >= end {
+        return 0.0;
+    }
+    let window = &sequence[start..end];
+    match &site.kind {
+        CandidateEventKind::Palindrome => palindrome_match_score(window),
+        CandidateEventKind::Motif(motif) => motif_match_score(window, motif),
+        CandidateEventKind::StrandSpecific => strand_asymmetry_score(window),
+    }
+}
+
+/// Converts a `[0, 1]` match score into a Bayes factor (likelihood ratio):
+/// `> 1` favors the event, `< 1` disfavors it. Scores are clamped away
+/// from the boundary so the ratio never blows up to infinity.
+fn likelihood_ratio(score: f64) -> f64 {
+    let clamped = score.clamp(1e-3, 1.0 - 1e-3);
+    clamped / (1.0 - clamped)
+}
+
+/// Scores every `candidates` site against `sequence`, discounting its
+/// evidence by `region_coord.se` (higher evolution-entropy means less
+/// trust in the local match), and emits calls whose posterior probability
+/// clears `settings.probability_threshold`, ranked by descending log-odds.
+pub fn call_candidate_sites(
+    sequence: &str,
+    candidates: &[CandidateSite],
+    region_coord: &SCoord,
+    settings: &CallingSettings,
+) -> Vec<PosteriorCall> {
+    let sequence = sequence.as_bytes();
+    let prior = settings.prior.clamp(1e-6, 1.0 - 1e-6);
+    let prior_odds = prior / (1.0 - prior);
+    let uncertainty_discount = 1.0 - region_coord.se.clamp(0.0, 1.0);
+
+    let mut calls: Vec<PosteriorCall> = candidates
+        .iter()
+        .filter_map(|site| {
+            let score = match_score(sequence, site);
+            let raw_ratio = likelihood_ratio(score);
+            let effective_ratio = raw_ratio.powf(uncertainty_discount);
+
+            let posterior_odds = prior_odds * effective_ratio;
+            let probability = posterior_odds / (1.0 + posterior_odds);
+            if probability < settings.probability_threshold {
+                return None;
+            }
+
+            let log_odds = posterior_odds.ln();
+            let window_len = site.end.saturating_sub(site.start).max(1) as f64;
+            let sigma = 1.0 / window_len.sqrt();
+            let lo_odds = (log_odds - 1.96 * sigma).exp();
+            let hi_odds = (log_odds + 1.96 * sigma).exp();
+            let confidence_interval = (lo_odds / (1.0 + lo_odds), hi_odds / (1.0 + hi_odds));
+
+            Some(PosteriorCall {
+                site: site.clone(),
+                probability,
+                log_odds,
+                confidence_interval,
+                significance_score: probability,
+            })
+        })
+        .collect();
+
+    calls.sort_by(|a, b| b.log_odds.partial_cmp(&a.log_odds).unwrap_or(std::cmp::Ordering::Equal));
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(se: f64) -> SCoord {
+        SCoord::new(0.2, 0.2, se).unwrap()
+    }
+
+    #[test]
+    fn test_palindrome_call_emitted_for_perfect_palindrome() {
+        let sequence = "GAATTC"; // EcoRI site, a perfect palindrome
+        let candidates = vec![CandidateSite {
+            start: 0,
+            end: 6,
+            kind: CandidateEventKind::Palindrome,
+        }];
+        let settings = CallingSettings::default();
+        let calls = call_candidate_sites(sequence, &candidates, &coord(0.1), &settings);
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].probability > settings.probability_threshold);
+    }
+
+    #[test]
+    fn test_non_palindrome_below_threshold_is_not_called() {
+        let sequence = "AAAAAA";
+        let candidates = vec![CandidateSite {
+            start: 0,
+            end: 6,
+            kind: CandidateEventKind::Palindrome,
+        }];
+        let settings = CallingSettings::default();
+        let calls = call_candidate_sites(sequence, &candidates, &coord(0.1), &settings);
+        assert!(calls.is_empty());
+    }
+
+    #[test]
+    fn test_motif_match_scores_exact_consensus_highly() {
+        let sequence = "TTTTATATAAGGGG";
+        let candidates = vec![CandidateSite {
+            start: 5,
+            end: 11,
+            kind: CandidateEventKind::Motif("TATAAG".to_string()),
+        }];
+        let settings = CallingSettings::default();
+        let calls = call_candidate_sites(sequence, &candidates, &coord(0.1), &settings);
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].log_odds > 0.0);
+    }
+
+    #[test]
+    fn test_high_region_entropy_discounts_evidence_toward_prior() {
+        let sequence = "GAATTC";
+        let candidates = vec![CandidateSite {
+            start: 0,
+            end: 6,
+            kind: CandidateEventKind::Palindrome,
+        }];
+        let settings = CallingSettings {
+            prior: 0.1,
+            probability_threshold: 0.0,
+        };
+        let confident = call_candidate_sites(sequence, &candidates, &coord(0.0), &settings);
+        let uncertain = call_candidate_sites(sequence, &candidates, &coord(1.0), &settings);
+
+        assert!(confident[0].probability > uncertain[0].probability);
+        assert!((uncertain[0].probability - settings.prior).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_calls_are_ranked_by_descending_log_odds() {
+        let sequence = "GAATTCAAAAAAGAATTC";
+        let candidates = vec![
+            CandidateSite { start: 0, end: 6, kind: CandidateEventKind::Palindrome },
+            CandidateSite { start: 6, end: 12, kind: CandidateEventKind::Palindrome },
+            CandidateSite { start: 12, end: 18, kind: CandidateEventKind::Palindrome },
+        ];
+        let settings = CallingSettings { prior: 0.1, probability_threshold: 0.0 };
+        let calls = call_candidate_sites(sequence, &candidates, &coord(0.1), &settings);
+        for pair in calls.windows(2) {
+            assert!(pair[0].log_odds >= pair[1].log_odds);
+        }
+    }
+
+    #[test]
+    fn test_confidence_interval_contains_point_probability() {
+        let sequence = "GAATTC";
+        let candidates = vec![CandidateSite {
+            start: 0,
+            end: 6,
+            kind: CandidateEventKind::Palindrome,
+        }];
+        let settings = CallingSettings::default();
+        let calls = call_candidate_sites(sequence, &candidates, &coord(0.1), &settings);
+        let (lo, hi) = calls[0].confidence_interval;
+        assert!(lo <= calls[0].probability + 1e-9);
+        assert!(hi >= calls[0].probability - 1e-9);
+    }
+
+    #[test]
+    fn test_strand_specific_asymmetry_scoring() {
+        let sequence = "GGGGGGCCCCCC"; // balanced G/C, low asymmetry
+        let candidates = vec![CandidateSite {
+            start: 0,
+            end: 12,
+            kind: CandidateEventKind::StrandSpecific,
+        }];
+        let settings = CallingSettings { prior: 0.5, probability_threshold: 0.0 };
+        let calls = call_candidate_sites(sequence, &candidates, &coord(0.1), &settings);
+        assert_eq!(calls.len(), 1);
+        assert!(calls[0].probability < 0.6);
+    }
+
+    #[test]
+    fn test_out_of_range_site_scores_zero_and_is_filtered() {
+        let sequence = "GAATTC";
+        let candidates = vec![CandidateSite {
+            start: 100,
+            end: 106,
+            kind: CandidateEventKind::Palindrome,
+        }];
+        let settings = CallingSettings::default();
+        let calls = call_candidate_sites(sequence, &candidates, &coord(0.1), &settings);
+        assert!(calls.is_empty());
+    }
+}
@@ -0,0 +1,259 @@
+//! Genomic Annotation Engine: resolves which functional regions overlap
+//! a genomic oscillation window, and scores the overlap for dosage
+//! sensitivity.
+//!
+//! Backed by an augmented interval tree (each node also stores the
+//! maximum end-coordinate across its subtree) so overlap queries run in
+//! `O(log n + k)` rather than scanning every annotation.
+
+use serde::{Deserialize, Serialize};
+
+/// Coordinate namespace an annotation set was built against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GenomeBuild {
+    GRCh37,
+    GRCh38,
+}
+
+/// A single functional region (promoter, exon, regulatory element, ...)
+/// with its clinical-style dosage-sensitivity weights.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenomicAnnotation {
+    pub start: usize,
+    pub end: usize,
+    pub feature_type: String,
+    /// Baseline functional importance of this region, in `[0, 1]`.
+    pub functional_significance: f64,
+    /// Haploinsufficiency/triplosensitivity weight for this region, in
+    /// `[0, 1]`: how damaging a dosage change (loss or gain) here tends
+    /// to be, independent of the region's baseline function.
+    pub dosage_weight: f64,
+}
+
+impl GenomicAnnotation {
+    /// Composite significance combining baseline function with
+    /// dosage sensitivity, in `[0, 1]`.
+    pub fn composite_significance(&self) -> f64 {
+        ((self.functional_significance + self.dosage_weight) / 2.0).clamp(0.0, 1.0)
+    }
+
+    fn overlaps(&self, start: usize, end: usize) -> bool {
+        self.start <= end && self.end >= start
+    }
+}
+
+/// A [`GenomicAnnotation`] returned from an overlap query, alongside its
+/// composite significance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotationMatch {
+    pub annotation: GenomicAnnotation,
+    pub composite_significance: f64,
+}
+
+struct IntervalNode {
+    start: usize,
+    end: usize,
+    annotation_index: usize,
+    max_end: usize,
+    left: Option<Box<IntervalNode>>,
+    right: Option<Box<IntervalNode>>,
+}
+
+fn build_node(indices: &[usize], annotations: &[GenomicAnnotation]) -> Option<Box<IntervalNode>> {
+    if indices.is_empty() {
+        return None;
+    }
+    let mid = indices.len() / 2;
+    let annotation_index = indices[mid];
+    let left = build_node(&indices[..mid], annotations);
+    let right = build_node(&indices[mid + 1..], annotations);
+
+    let own = &annotations[annotation_index];
+    let mut max_end = own.end;
+    if let Some(node) = &left {
+        max_end = max_end.max(node.max_end);
+    }
+    if let Some(node) = &right {
+        max_end = max_end.max(node.max_end);
+    }
+
+    Some(Box::new(IntervalNode {
+        start: own.start,
+        end: own.end,
+        annotation_index,
+        max_end,
+        left,
+        right,
+    }))
+}
+
+fn query_node(node: &IntervalNode, start: usize, end: usize, out: &mut Vec<usize>) {
+    if let Some(left) = &node.left {
+        if left.max_end >= start {
+            query_node(left, start, end, out);
+        }
+    }
+
+    if node.start <= end && node.end >= start {
+        out.push(node.annotation_index);
+    }
+
+    if node.start <= end {
+        if let Some(right) = &node.right {
+            query_node(right, start, end, out);
+        }
+    }
+}
+
+/// Augmented interval tree over a single chromosome's annotation set for
+/// one [`GenomeBuild`] coordinate namespace.
+pub struct AnnotationEngine {
+    build: GenomeBuild,
+    chromosome: String,
+    annotations: Vec<GenomicAnnotation>,
+    root: Option<Box<IntervalNode>>,
+}
+
+impl AnnotationEngine {
+    /// Ingest a serialized annotation table for one chromosome under a
+    /// given coordinate namespace, building the interval tree.
+    pub fn from_table(
+        build: GenomeBuild,
+        chromosome: impl Into<String>,
+        annotations: Vec<GenomicAnnotation>,
+    ) -> Self {
+        let mut indices: Vec<usize> = (0..annotations.len()).collect();
+        indices.sort_by_key(|&i| annotations[i].start);
+        let root = build_node(&indices, &annotations);
+        Self {
+            build,
+            chromosome: chromosome.into(),
+            annotations,
+            root,
+        }
+    }
+
+    pub fn build(&self) -> GenomeBuild {
+        self.build
+    }
+
+    pub fn chromosome(&self) -> &str {
+        &self.chromosome
+    }
+
+    /// Every feature overlapping `[start, end]`, ranked by descending
+    /// composite significance.
+    pub fn query_overlaps(&self, start: usize, end: usize) -> Vec<AnnotationMatch> {
+        let mut indices = Vec::new();
+        if let Some(root) = &self.root {
+            query_node(root, start, end, &mut indices);
+        }
+
+        let mut matches: Vec<AnnotationMatch> = indices
+            .into_iter()
+            .map(|i| {
+                let annotation = self.annotations[i].clone();
+                let composite_significance = annotation.composite_significance();
+                AnnotationMatch {
+                    annotation,
+                    composite_significance,
+                }
+            })
+            .collect();
+        matches.sort_by(|a, b| {
+            b.composite_significance
+                .partial_cmp(&a.composite_significance)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        matches
+    }
+
+    /// Sum of composite significances across every feature overlapping
+    /// `[start, end]`: the dosage-sensitivity score driving
+    /// `GeneNetworkOscillation.amplitude_modulation` and a
+    /// `BiologicalPattern.significance_score`.
+    pub fn accumulated_dosage_score(&self, start: usize, end: usize) -> f64 {
+        self.query_overlaps(start, end)
+            .iter()
+            .map(|m| m.composite_significance)
+            .sum()
+    }
+}
+
+/// Re-checks every node's `overlaps` helper is only used as the
+/// reference predicate in tests; the tree's recursive descent is the
+/// real O(log n + k) path.
+#[cfg(test)]
+fn linear_scan_overlaps(annotations: &[GenomicAnnotation], start: usize, end: usize) -> usize {
+    annotations.iter().filter(|a| a.overlaps(start, end)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotation(start: usize, end: usize, feature_type: &str, functional: f64, dosage: f64) -> GenomicAnnotation {
+        GenomicAnnotation {
+            start,
+            end,
+            feature_type: feature_type.to_string(),
+            functional_significance: functional,
+            dosage_weight: dosage,
+        }
+    }
+
+    fn sample_engine() -> AnnotationEngine {
+        let annotations = vec![
+            annotation(100, 200, "promoter", 0.8, 0.6),
+            annotation(150, 300, "exon", 0.9, 0.9),
+            annotation(500, 600, "regulatory", 0.4, 0.2),
+            annotation(250, 260, "exon", 0.7, 0.3),
+        ];
+        AnnotationEngine::from_table(GenomeBuild::GRCh38, "chr1", annotations)
+    }
+
+    #[test]
+    fn test_query_overlaps_matches_linear_scan_count() {
+        let engine = sample_engine();
+        let matches = engine.query_overlaps(180, 260);
+        let expected = linear_scan_overlaps(&engine.annotations, 180, 260);
+        assert_eq!(matches.len(), expected);
+    }
+
+    #[test]
+    fn test_query_overlaps_excludes_non_overlapping_regions() {
+        let engine = sample_engine();
+        let matches = engine.query_overlaps(0, 50);
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_query_overlaps_ranked_by_descending_composite_significance() {
+        let engine = sample_engine();
+        let matches = engine.query_overlaps(150, 260);
+        for pair in matches.windows(2) {
+            assert!(pair[0].composite_significance >= pair[1].composite_significance);
+        }
+    }
+
+    #[test]
+    fn test_accumulated_dosage_score_sums_overlapping_matches() {
+        let engine = sample_engine();
+        let matches = engine.query_overlaps(150, 260);
+        let expected: f64 = matches.iter().map(|m| m.composite_significance).sum();
+        assert!((engine.accumulated_dosage_score(150, 260) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_accumulated_dosage_score_zero_when_no_overlap() {
+        let engine = sample_engine();
+        assert_eq!(engine.accumulated_dosage_score(10_000, 10_100), 0.0);
+    }
+
+    #[test]
+    fn test_engine_retains_build_and_chromosome_namespace() {
+        let engine = sample_engine();
+        assert_eq!(engine.build(), GenomeBuild::GRCh38);
+        assert_eq!(engine.chromosome(), "chr1");
+    }
+}
@@ -0,0 +1,406 @@
+//! Kalman Oscillator: a two-state Kalman filter that tracks the dominant
+//! frequency and phase of a sampled oscillatory signal, replacing
+//! placeholder frequency/health estimates with a real recursive estimator.
+//!
+//! The filter state is `[phase_offset, frequency]` with a 2x2 covariance
+//! `P`. Between samples, `predict` advances phase by `frequency * dt` and
+//! inflates `P` by a process-noise term derived from an oscillator error
+//! budget expressed in parts-per-million (so uncertainty grows
+//! monotonically across sample gaps). Each sample yields a measured
+//! instantaneous phase via zero-crossing detection (the period between the
+//! two most recent crossings calibrates how far around the cycle later
+//! samples have travelled); `update` then applies the standard Kalman gain
+//! to correct the state and shrink `P`.
+//!
+//! [`OscillatorAssessment`] accumulates estimates over a longer run and
+//! derives an [`OscillatorHealth`] summary, discarding its history whenever
+//! a new estimate jumps too far from the running estimate so a regime
+//! change doesn't get smoothed in with data from before it.
+
+use std::f64::consts::PI;
+
+fn wrap_to_pi(angle: f64) -> f64 {
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    wrapped
+}
+
+/// Tuning parameters for a [`KalmanOscillator`].
+#[derive(Debug, Clone, Copy)]
+pub struct KalmanOscillatorConfig {
+    /// Oscillator frequency-drift budget, in parts per million of the
+    /// expected frequency, driving the per-sample process noise.
+    pub oscillator_error_ppm: f64,
+    /// Phase measurement noise variance (`R`).
+    pub measurement_noise: f64,
+    /// Maximum allowed fractional deviation of the frequency estimate from
+    /// `expected_frequency` before it is clamped back.
+    pub max_frequency_error: f64,
+}
+
+impl Default for KalmanOscillatorConfig {
+    fn default() -> Self {
+        Self {
+            oscillator_error_ppm: 15.0,
+            measurement_noise: 0.1,
+            max_frequency_error: 0.5,
+        }
+    }
+}
+
+/// One filtered reading: the smoothed frequency/phase and how uncertain
+/// the filter currently is about the phase.
+#[derive(Debug, Clone, Copy)]
+pub struct KalmanEstimate {
+    pub frequency: f64,
+    pub phase: f64,
+    pub residual_variance: f64,
+}
+
+/// Recursive two-state (`[phase, frequency]`) Kalman filter for a single
+/// dominant oscillation, fed one sample at a time via [`Self::step`].
+#[derive(Debug, Clone)]
+pub struct KalmanOscillator {
+    config: KalmanOscillatorConfig,
+    sampling_rate: f64,
+    expected_frequency: f64,
+    phase: f64,
+    frequency: f64,
+    covariance: [[f64; 2]; 2],
+    prev_sample: Option<f64>,
+    samples_since_crossing: usize,
+    last_period_samples: Option<f64>,
+    convergence_failures: usize,
+}
+
+impl KalmanOscillator {
+    pub fn new(sampling_rate: f64, expected_frequency: f64, config: KalmanOscillatorConfig) -> Self {
+        Self {
+            config,
+            sampling_rate,
+            expected_frequency,
+            phase: 0.0,
+            frequency: expected_frequency,
+            covariance: [[1.0, 0.0], [0.0, 1.0]],
+            prev_sample: None,
+            samples_since_crossing: 0,
+            last_period_samples: None,
+            convergence_failures: 0,
+        }
+    }
+
+    fn dt(&self) -> f64 {
+        1.0 / self.sampling_rate
+    }
+
+    /// Advances the state by one `dt` and inflates `P` with a process-noise
+    /// term proportional to the ppm-scaled oscillator error budget, so
+    /// uncertainty grows monotonically between measurements.
+    fn predict(&mut self) {
+        let dt = self.dt();
+        self.phase = wrap_to_pi(self.phase + 2.0 * PI * self.frequency * dt);
+
+        let sigma_f = self.expected_frequency * self.config.oscillator_error_ppm * 1e-6;
+        let q_freq = (sigma_f * dt).powi(2);
+        let q_phase = q_freq * dt * dt + 1e-12;
+
+        // F = [[1, 2*pi*dt], [0, 1]]; P' = F P F^T + Q.
+        let p00 = self.covariance[0][0];
+        let p01 = self.covariance[0][1];
+        let p11 = self.covariance[1][1];
+        let f01 = 2.0 * PI * dt;
+
+        let new_p00 = p00 + f01 * (p01 + p01 + f01 * p11);
+        let new_p01 = p01 + f01 * p11;
+        let new_p11 = p11;
+
+        self.covariance = [[new_p00 + q_phase, new_p01], [new_p01, new_p11 + q_freq]];
+    }
+
+    /// Corrects the state against a measured phase using the standard
+    /// Kalman gain `K = P H^T (H P H^T + R)^-1` with `H = [1, 0]`, then
+    /// clamps the frequency to within `max_frequency_error` of
+    /// `expected_frequency`, tracking repeated clamps as convergence
+    /// failures.
+    fn update(&mut self, measured_phase: f64) {
+        let r = self.config.measurement_noise;
+        let p00 = self.covariance[0][0];
+        let p01 = self.covariance[0][1];
+        let p11 = self.covariance[1][1];
+
+        let innovation_covariance = p00 + r;
+        if innovation_covariance <= 0.0 {
+            return;
+        }
+        let k0 = p00 / innovation_covariance;
+        let k1 = p01 / innovation_covariance;
+
+        let innovation = wrap_to_pi(measured_phase - self.phase);
+        self.phase = wrap_to_pi(self.phase + k0 * innovation);
+        self.frequency += k1 * innovation;
+
+        self.covariance = [
+            [p00 - k0 * p00, p01 - k0 * p01],
+            [p01 - k1 * p00, p11 - k1 * p01],
+        ];
+
+        let max_deviation = self.expected_frequency * self.config.max_frequency_error;
+        let deviation = self.frequency - self.expected_frequency;
+        if deviation.abs() > max_deviation {
+            self.frequency = self.expected_frequency + max_deviation.copysign(deviation);
+            self.convergence_failures += 1;
+        }
+    }
+
+    /// Detects a zero crossing between the previous and current sample via
+    /// linear interpolation, using the most recently completed cycle's
+    /// length to derive a measured phase for samples in between. Returns
+    /// `None` until the first upward zero crossing has been seen.
+    fn measure_phase(&mut self, sample: f64) -> Option<f64> {
+        let measured = if let Some(prev) = self.prev_sample {
+            if prev <= 0.0 && sample > 0.0 {
+                let frac = prev.abs() / (prev.abs() + sample.abs()).max(1e-12);
+                let period_samples = self.samples_since_crossing as f64 + frac;
+                self.last_period_samples = Some(period_samples.max(1.0));
+                self.samples_since_crossing = 0;
+                Some(0.0) // crossing point defines phase zero
+            } else {
+                self.samples_since_crossing += 1;
+                self.last_period_samples.map(|period| {
+                    wrap_to_pi(2.0 * PI * self.samples_since_crossing as f64 / period)
+                })
+            }
+        } else {
+            self.samples_since_crossing += 1;
+            None
+        };
+
+        self.prev_sample = Some(sample);
+        measured
+    }
+
+    /// Feeds one new sample through predict-then-update and returns the
+    /// current smoothed estimate.
+    pub fn step(&mut self, sample: f64) -> KalmanEstimate {
+        self.predict();
+        if let Some(measured_phase) = self.measure_phase(sample) {
+            self.update(measured_phase);
+        }
+        KalmanEstimate {
+            frequency: self.frequency,
+            phase: self.phase,
+            residual_variance: self.covariance[0][0],
+        }
+    }
+
+    pub fn convergence_failures(&self) -> usize {
+        self.convergence_failures
+    }
+}
+
+/// Long-running health assessment built from a stream of
+/// [`KalmanEstimate`]s: discards its retained history whenever a new
+/// estimate's frequency jumps more than `regime_change_threshold` (a
+/// fraction of the running estimate) away, so pre- and post-transition
+/// data never get fused together.
+#[derive(Debug, Clone)]
+pub struct OscillatorAssessment {
+    regime_change_threshold: f64,
+    running_estimate: Option<f64>,
+    history: Vec<KalmanEstimate>,
+    total_samples: usize,
+}
+
+impl OscillatorAssessment {
+    pub fn new(regime_change_threshold: f64) -> Self {
+        Self {
+            regime_change_threshold,
+            running_estimate: None,
+            history: Vec::new(),
+            total_samples: 0,
+        }
+    }
+
+    /// Records one filter estimate, resetting the retained history first
+    /// if it represents a regime change.
+    pub fn observe(&mut self, estimate: KalmanEstimate) {
+        self.total_samples += 1;
+        if let Some(running) = self.running_estimate {
+            let scale = running.abs().max(1e-9);
+            if (estimate.frequency - running).abs() / scale > self.regime_change_threshold {
+                self.history.clear();
+            }
+        }
+        self.running_estimate = Some(estimate.frequency);
+        self.history.push(estimate);
+    }
+
+    /// Derives a health summary from the currently retained history.
+    /// `oscillatory_health` and `coherence_score` are both monotone
+    /// decreasing functions of the average residual (phase) variance
+    /// across the retained window, so a well-converged filter scores near
+    /// `1.0` and a poorly-converged one scores near `0.0`. `risk_factor` is
+    /// set once the filter has repeatedly failed to converge.
+    pub fn health(&self, convergence_failures: usize) -> OscillatorHealth {
+        if self.history.is_empty() {
+            return OscillatorHealth {
+                smoothed_frequency: 0.0,
+                residual_variance: 0.0,
+                oscillatory_health: 0.0,
+                coherence_score: 0.0,
+                risk_factor: None,
+            };
+        }
+
+        let n = self.history.len() as f64;
+        let smoothed_frequency = self.history.iter().map(|e| e.frequency).sum::<f64>() / n;
+        let residual_variance = self.history.iter().map(|e| e.residual_variance).sum::<f64>() / n;
+
+        let oscillatory_health = 1.0 / (1.0 + residual_variance);
+        let frequency_spread = self.history.iter().map(|e| (e.frequency - smoothed_frequency).powi(2)).sum::<f64>() / n;
+        let coherence_score = 1.0 / (1.0 + residual_variance + frequency_spread);
+
+        let risk_factor = if convergence_failures >= 3 {
+            Some(convergence_failures as f64 / self.total_samples.max(1) as f64)
+        } else {
+            None
+        };
+
+        OscillatorHealth {
+            smoothed_frequency,
+            residual_variance,
+            oscillatory_health,
+            coherence_score,
+            risk_factor,
+        }
+    }
+}
+
+/// Health summary derived from a [`KalmanOscillator`]/[`OscillatorAssessment`]
+/// pair: the smoothed frequency, its residual variance, and derived
+/// `[0, 1]`-scaled health/coherence scores.
+#[derive(Debug, Clone, Copy)]
+pub struct OscillatorHealth {
+    pub smoothed_frequency: f64,
+    pub residual_variance: f64,
+    pub oscillatory_health: f64,
+    pub coherence_score: f64,
+    pub risk_factor: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_tone(filter: &mut KalmanOscillator, freq: f64, fs: f64, n: usize) -> KalmanEstimate {
+        let mut last = KalmanEstimate {
+            frequency: freq,
+            phase: 0.0,
+            residual_variance: 0.0,
+        };
+        for i in 0..n {
+            let x = (2.0 * PI * freq * i as f64 / fs).sin();
+            last = filter.step(x);
+        }
+        last
+    }
+
+    #[test]
+    fn test_filter_converges_close_to_true_frequency() {
+        let fs = 256.0;
+        let freq = 10.0;
+        let mut filter = KalmanOscillator::new(fs, freq, KalmanOscillatorConfig::default());
+        let estimate = run_tone(&mut filter, freq, fs, 20 * fs as usize / 10);
+
+        assert!((estimate.frequency - freq).abs() < 1.0, "estimate = {}", estimate.frequency);
+    }
+
+    #[test]
+    fn test_residual_variance_shrinks_as_samples_accumulate() {
+        let fs = 256.0;
+        let freq = 10.0;
+        let mut filter = KalmanOscillator::new(fs, freq, KalmanOscillatorConfig::default());
+
+        let early = filter.step(0.0);
+        for i in 1..512 {
+            let x = (2.0 * PI * freq * i as f64 / fs).sin();
+            filter.step(x);
+        }
+        let late = filter.step(1.0);
+
+        assert!(late.residual_variance < early.residual_variance);
+    }
+
+    #[test]
+    fn test_frequency_estimate_is_bounded_by_max_error() {
+        let fs = 256.0;
+        let expected = 10.0;
+        let config = KalmanOscillatorConfig {
+            max_frequency_error: 0.2,
+            ..KalmanOscillatorConfig::default()
+        };
+        let mut filter = KalmanOscillator::new(fs, expected, config);
+        // Feed a wildly different frequency; the estimate must not run away.
+        let estimate = run_tone(&mut filter, 80.0, fs, 2000);
+
+        assert!((estimate.frequency - expected).abs() <= expected * 0.2 + 1e-6);
+    }
+
+    #[test]
+    fn test_assessment_accumulates_history_for_stable_signal() {
+        let fs = 256.0;
+        let freq = 10.0;
+        let mut filter = KalmanOscillator::new(fs, freq, KalmanOscillatorConfig::default());
+        let mut assessment = OscillatorAssessment::new(0.5);
+
+        for i in 0..512 {
+            let x = (2.0 * PI * freq * i as f64 / fs).sin();
+            let estimate = filter.step(x);
+            assessment.observe(estimate);
+        }
+
+        let health = assessment.health(filter.convergence_failures());
+        assert!(health.oscillatory_health > 0.0);
+        assert!((health.smoothed_frequency - freq).abs() < 2.0);
+    }
+
+    #[test]
+    fn test_assessment_discards_history_on_regime_change() {
+        let mut assessment = OscillatorAssessment::new(0.1);
+        for _ in 0..10 {
+            assessment.observe(KalmanEstimate {
+                frequency: 10.0,
+                phase: 0.0,
+                residual_variance: 0.01,
+            });
+        }
+        assert_eq!(assessment.health(0).smoothed_frequency, 10.0);
+
+        assessment.observe(KalmanEstimate {
+            frequency: 40.0,
+            phase: 0.0,
+            residual_variance: 0.01,
+        });
+
+        let health = assessment.health(0);
+        assert!((health.smoothed_frequency - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_risk_factor_absent_without_history_despite_failures() {
+        let assessment = OscillatorAssessment::new(0.5);
+        let health = assessment.health(5);
+        assert!(health.risk_factor.is_none());
+    }
+
+    #[test]
+    fn test_risk_factor_present_with_history_and_failures() {
+        let mut assessment = OscillatorAssessment::new(0.5);
+        assessment.observe(KalmanEstimate {
+            frequency: 10.0,
+            phase: 0.0,
+            residual_variance: 0.01,
+        });
+        let health = assessment.health(4);
+        assert!(health.risk_factor.is_some());
+    }
+}
@@ -9,24 +9,95 @@
 //! - `ternary_ops`: Ternary tree operators (ENCODE, DECODE, TRISECT)
 //! - `neural_ops`: Neural operators (CONSCIOUSNESS, MEMORY, DREAM, WAKE)
 //! - `dynamics_ops`: Kuramoto dynamics (KURAMOTO, PHASE_LOCK, CASCADE)
+//! - `legion_ops`: LEGION relaxation-oscillator dynamics (LEGION_STEP), complementing Kuramoto
 //! - `poincare_ops`: Poincare computing (COMPLETE, TARGET, EQUILIBRIUM)
 //! - `charge_ops`: Charge transport (CONSERVE, REDISTRIBUTE)
+//! - `diis`: Direct Inversion in the Iterative Subspace convergence acceleration
+//! - `spectral`: FFT-based spectral analysis of sampled time series
+//! - `streaming_spectral`: Sliding-window Goertzel filters for sample-by-sample signals
+//! - `perception_source`: Pluggable (closure or learned) perception/thought drives
+//! - `transfer_matching`: Banded DTW similarity for cross-domain oscillatory pattern transfer
+//! - `genomic_ops`: Interval-tree genomic annotation overlap and dosage-sensitivity scoring
+//! - `genomic_calling`: Bayesian posterior calling for palindrome/motif/strand-asymmetry candidates
+//! - `phase_amplitude_coupling`: Tort modulation-index cross-frequency coupling analysis
+//! - `superresolution`: Frank-Wolfe sparse-measure spectral peak recovery
+//! - `bayesian_evidence`: Calibrated posterior probabilities over latent health regimes
+//! - `batch_kernels`: Lane-chunked batch entropy/centroid/connectivity kernels
+//! - `kalman_oscillator`: Two-state Kalman filter for oscillation frequency/phase tracking
+//! - `sampling_schedule`: YAML-configurable inclusion/exclusion epochs for constrained sampling
+//! - `spatial_pattern_db`: K-d-tree-indexed, persistable cross-domain pattern store with warm-start
+//! - `wasm_bindings` (behind the `wasm` feature): WASM entry points for the Poincare/charge operator pipeline
 
+pub mod batch_kernels;
+pub mod bayesian_evidence;
 pub mod charge_ops;
+pub mod diis;
 pub mod dynamics_ops;
+pub mod genomic_calling;
+pub mod genomic_ops;
+pub mod kalman_oscillator;
+pub mod legion_ops;
 pub mod neural_ops;
 pub mod partition_ops;
+pub mod perception_source;
+pub mod phase_amplitude_coupling;
 pub mod poincare_ops;
+pub mod sampling_schedule;
 pub mod sentropy_ops;
+pub mod spatial_pattern_db;
+pub mod spectral;
+pub mod streaming_spectral;
+pub mod superresolution;
 pub mod ternary_ops;
+pub mod transfer_matching;
+#[cfg(feature = "wasm")]
+pub mod wasm_bindings;
 
 // Re-export commonly used items
+pub use batch_kernels::{
+    batch_network_connectivity, batch_signal_entropy, batch_spectral_centroid, signal_entropy,
+    spectral_centroid,
+};
+pub use bayesian_evidence::{
+    cellular_evidence_quality, event_confidence, fdr_controlled_selection, posterior,
+    severity_score, theta_grid_from_atp, AtpConstraints, Regime, RegimeModel,
+};
 pub use dynamics_ops::{
-    cascade, coherence, critical_coupling, kuramoto, kuramoto_with_drug, phase_lock,
-    simulate_kuramoto, variance, KuramotoState,
+    cascade, coherence, critical_coupling, kuramoto, kuramoto_stochastic, kuramoto_with_drug,
+    kuramoto_with_integrator, local_order_parameter, phase_lock, simulate_kuramoto,
+    simulate_kuramoto_stochastic, sync_transition_sweep, variance, Euler, Integrator,
+    KuramotoState, RungeKutta4, SyncTransitionResult,
+};
+pub use genomic_calling::{
+    call_candidate_sites, CallingSettings, CandidateEventKind, CandidateSite, PosteriorCall,
+};
+pub use genomic_ops::{AnnotationEngine, AnnotationMatch, GenomeBuild, GenomicAnnotation};
+pub use kalman_oscillator::{
+    KalmanEstimate, KalmanOscillator, KalmanOscillatorConfig, OscillatorAssessment, OscillatorHealth,
 };
+pub use legion_ops::{legion_step, LegionParams, LegionState};
 pub use neural_ops::{consciousness, dream, evolve_mental_state, memory, wake};
-pub use partition_ops::{adjacent_coords, capacity, coords, d_cat, partition, partition_to_sentropy};
+pub use partition_ops::{
+    adjacent_coords, capacity, coords, d_cat, double_excitation_coords, partition,
+    partition_to_sentropy, quiet_softmax, DoubleExcitation,
+};
+pub use perception_source::{ClosurePerceptionSource, DriveInputs, PerceptionSource};
+pub use phase_amplitude_coupling::{
+    cross_frequency_coupling_analysis, modulation_index, phase_amplitude_coupling,
+    FrequencyBandRange, PhaseCoupling, DEFAULT_PAC_BINS,
+};
+pub use poincare_ops::trajectory_optimizer::{TrajectoryOptimizer, TrajectoryPlan};
 pub use poincare_ops::{complete, equilibrium, satisfy, target, CompletionResult};
+pub use sampling_schedule::{Epoch, EpochKind, SamplingSchedule, TimeUnit, Visibility};
 pub use sentropy_ops::{grad_s, minimize_free_energy, navigate, update_se, update_sk, update_st};
+pub use spatial_pattern_db::{CrossDomainPattern, PatternDatabase, PatternMatch, WarmStartSeed};
+pub use spectral::{dominant_frequency, power_spectrum, spectrum, PowerSpectrum, Spectrum};
+pub use streaming_spectral::{
+    BandState, FrequencyAnalyzer, FrequencyBand, GoertzelBandTracker, OscillatoryIndicator,
+};
+pub use superresolution::{dominant_frequencies, recover_peaks, SuperresolutionConfig};
 pub use ternary_ops::{decode, decode_float, encode, encode_float, ternary_search, trisect};
+pub use transfer_matching::{
+    dtw_distance, transfer_efficiency, CrossDomainOscillatoryPattern, OscillatorySignature,
+    PatternTransferDb, PerformanceMetrics, TransferCase, TransferMatch, WarmStartParams,
+};
@@ -2,14 +2,20 @@
 //!
 //! Implements neural-level operations for consciousness computation.
 
+use crate::perception_source::PerceptionSource;
 use ndarray::Array1;
+use num_traits::{Float, ToPrimitive};
 use vb_core::constants::{TAU_PERCEPTION, TAU_THOUGHT};
 use vb_core::types::MentalState;
 
 /// CONSCIOUSNESS operator: Compute consciousness level.
 ///
 /// C = P_decay * T_decay * gamma * gamma_f
-pub fn consciousness(p_decay: f64, t_decay: f64, gamma: f64, gamma_f: f64) -> f64 {
+///
+/// Generic over the scalar float type so callers can run large oscillator
+/// populations in `f32` (see [`vb_core::types::MentalStateF32`]) while the
+/// framework default stays `f64`.
+pub fn consciousness<S: Float>(p_decay: S, t_decay: S, gamma: S, gamma_f: S) -> S {
     p_decay * t_decay * gamma * gamma_f
 }
 
@@ -25,15 +31,15 @@ pub fn consciousness_frequency(omega_thought: f64, omega_perception: f64) -> f64
 /// MEMORY operator: Accumulated emotional change.
 ///
 /// M = ∫|dH/dt| dt
-pub fn memory(h_field: &Array1<f64>, dt: f64) -> f64 {
+pub fn memory<S: Float>(h_field: &Array1<S>, dt: S) -> S {
     if h_field.len() < 2 {
-        return 0.0;
+        return S::zero();
     }
 
-    let mut m = 0.0;
+    let mut m = S::zero();
     for i in 1..h_field.len() {
         let dh_dt = (h_field[i] - h_field[i - 1]) / dt;
-        m += dh_dt.abs() * dt;
+        m = m + dh_dt.abs() * dt;
     }
     m
 }
@@ -58,7 +64,7 @@ pub fn wake(state: &MentalState, perception_level: f64) -> MentalState {
 }
 
 /// Decay evolution: d(curve)/dt = -curve/tau + input_rate
-pub fn decay_evolve(curve: f64, tau: f64, dt: f64, input_rate: f64) -> f64 {
+pub fn decay_evolve<S: Float>(curve: S, tau: S, dt: S, input_rate: S) -> S {
     curve * (-dt / tau).exp() + input_rate * dt
 }
 
@@ -75,16 +81,26 @@ pub fn predict_emotion(h_now: f64, dm_dt: f64, delta_t: f64) -> f64 {
 }
 
 /// Evolve mental state by one time step.
-pub fn evolve_mental_state(
-    state: &MentalState,
-    dt: f64,
-    perception_input: f64,
-    thought_input: f64,
-    dh_dt: f64,
-) -> MentalState {
+pub fn evolve_mental_state<S: Float>(
+    state: &MentalState<S>,
+    dt: S,
+    perception_input: S,
+    thought_input: S,
+    dh_dt: S,
+) -> MentalState<S> {
     // Apply decays with input
-    let new_p_decay = decay_evolve(state.p_decay, TAU_PERCEPTION, dt, perception_input);
-    let new_t_decay = decay_evolve(state.t_decay, TAU_THOUGHT, dt, thought_input);
+    let new_p_decay = decay_evolve(
+        state.p_decay,
+        S::from(TAU_PERCEPTION).unwrap(),
+        dt,
+        perception_input,
+    );
+    let new_t_decay = decay_evolve(
+        state.t_decay,
+        S::from(TAU_THOUGHT).unwrap(),
+        dt,
+        thought_input,
+    );
 
     // Update memory
     let new_m = state.m + dh_dt.abs() * dt;
@@ -96,18 +112,53 @@ pub fn evolve_mental_state(
         s_coord: state.s_coord,
         partition: state.partition,
         timestamp: state.timestamp + dt,
-        p_decay: new_p_decay.clamp(0.0, 1.0),
-        t_decay: new_t_decay.clamp(0.0, 1.0),
+        p_decay: clamp_unit(new_p_decay),
+        t_decay: clamp_unit(new_t_decay),
         trajectory: state.trajectory.clone(),
     }
 }
 
+/// Clamp a scalar into [0, 1]; mirrors the primitive `f32`/`f64` `clamp`
+/// method, which `num_traits::Float` does not provide.
+fn clamp_unit<S: Float>(val: S) -> S {
+    val.max(S::zero()).min(S::one())
+}
+
 /// Generate consciousness time series.
-pub fn consciousness_time_series(
+pub fn consciousness_time_series<S: Float>(
+    initial_state: &MentalState<S>,
+    duration: S,
+    dt: S,
+    perception_profile: &dyn Fn(S) -> S,
+) -> (Array1<S>, Array1<S>) {
+    let n_steps = (duration / dt).to_usize().unwrap_or(0);
+    let mut times = Array1::zeros(n_steps);
+    let mut consciousness_values = Array1::zeros(n_steps);
+
+    let mut state = initial_state.clone();
+    let half = S::from(0.5).unwrap();
+
+    for i in 0..n_steps {
+        let t = S::from(i).unwrap() * dt;
+        times[i] = t;
+        consciousness_values[i] = state.consciousness();
+
+        let perception = perception_profile(t);
+        state = evolve_mental_state(&state, dt, perception, half, S::zero());
+    }
+
+    (times, consciousness_values)
+}
+
+/// Generate consciousness time series driven by a `PerceptionSource`,
+/// so learned models can replace the analytic `perception_profile`
+/// closure (and its hardcoded `thought_input = 0.5`) used by
+/// `consciousness_time_series`.
+pub fn consciousness_time_series_with_source(
     initial_state: &MentalState,
     duration: f64,
     dt: f64,
-    perception_profile: &dyn Fn(f64) -> f64,
+    source: &mut dyn PerceptionSource,
 ) -> (Array1<f64>, Array1<f64>) {
     let n_steps = (duration / dt) as usize;
     let mut times = Array1::zeros(n_steps);
@@ -120,8 +171,8 @@ pub fn consciousness_time_series(
         times[i] = t;
         consciousness_values[i] = state.consciousness();
 
-        let perception = perception_profile(t);
-        state = evolve_mental_state(&state, dt, perception, 0.5, 0.0);
+        let drive = source.sample(t, &state);
+        state = evolve_mental_state(&state, dt, drive.perception, drive.thought, 0.0);
     }
 
     (times, consciousness_values)
@@ -167,4 +218,46 @@ mod tests {
         let new_val = decay_evolve(1.0, 0.1, 0.01, 0.0);
         assert!(new_val < 1.0); // Should decay
     }
+
+    /// `decay_evolve` and `consciousness_time_series` should agree across
+    /// `f32` and `f64` within each type's own epsilon.
+    fn decay_and_series_within_epsilon<S: Float + std::fmt::Debug>() {
+        let curve = S::one();
+        let tau = S::from(0.1).unwrap();
+        let dt = S::from(0.01).unwrap();
+        let decayed = decay_evolve(curve, tau, dt, S::zero());
+        let expected = (-dt / tau).exp();
+        assert!((decayed - expected).abs() < S::epsilon() * S::from(10.0).unwrap());
+
+        let initial = MentalState::<S>::default();
+        let profile = |_t: S| S::from(0.5).unwrap();
+        let (times, c_values) =
+            consciousness_time_series(&initial, S::from(0.1).unwrap(), dt, &profile);
+        assert_eq!(times.len(), 10);
+        assert_eq!(c_values.len(), 10);
+    }
+
+    #[test]
+    fn test_decay_and_series_f64() {
+        decay_and_series_within_epsilon::<f64>();
+    }
+
+    #[test]
+    fn test_decay_and_series_f32() {
+        decay_and_series_within_epsilon::<f32>();
+    }
+
+    #[test]
+    fn test_consciousness_time_series_with_source() {
+        use crate::perception_source::ClosurePerceptionSource;
+
+        let initial = MentalState::default();
+        let mut source = ClosurePerceptionSource::with_default_thought(|t| 0.5 + 0.1 * t);
+
+        let (times, c_values) =
+            consciousness_time_series_with_source(&initial, 0.1, 0.01, &mut source);
+
+        assert_eq!(times.len(), 10);
+        assert_eq!(c_values.len(), 10);
+    }
 }
@@ -79,6 +79,42 @@ pub fn sentropy_to_partition(s_coord: &SCoord, n_max: i32) -> Result<PartitionCo
     PartitionCoord::new(n, l, m, s)
 }
 
+/// QUIET_SOFTMAX operator: Probability distribution over partition states
+/// based on categorical distance from a reference coordinate.
+///
+/// Computes logits `x_i = -beta * d_cat(reference, c_i)`, then a "quiet"
+/// softmax `exp(x_i) / (1 + sum_j exp(x_j))`, evaluated with every term
+/// (the `1` included) shifted by `m = max(0, max_i x_i)` for numerical
+/// stability: `exp(x_i - m) / (exp(-m) + sum_j exp(x_j - m))`. The extra `+1` lets the whole
+/// distribution decay toward zero when every state is far from
+/// `reference` (unlike an ordinary softmax, which always sums to one),
+/// avoiding overflow for large `beta` or large distances.
+pub fn quiet_softmax(
+    reference: &PartitionCoord,
+    states: &[PartitionCoord],
+    beta: f64,
+) -> Vec<(PartitionCoord, f64)> {
+    if states.is_empty() {
+        return Vec::new();
+    }
+
+    let logits: Vec<f64> = states
+        .iter()
+        .map(|c| -beta * d_cat(reference, c))
+        .collect();
+
+    let max_logit = logits.iter().cloned().fold(0.0, f64::max);
+
+    let exp_shifted: Vec<f64> = logits.iter().map(|&x| (x - max_logit).exp()).collect();
+    let denom = (-max_logit).exp() + exp_shifted.iter().sum::<f64>();
+
+    states
+        .iter()
+        .copied()
+        .zip(exp_shifted.into_iter().map(|e| e / denom))
+        .collect()
+}
+
 /// Find adjacent coordinates (D_cat = 1).
 pub fn adjacent_coords(coord: &PartitionCoord) -> Vec<PartitionCoord> {
     let mut adjacent = Vec::new();
@@ -147,6 +183,61 @@ pub fn adjacent_coords(coord: &PartitionCoord) -> Vec<PartitionCoord> {
     adjacent
 }
 
+/// Categorical overlap kernel `exp(-d_cat(c1, c2))`, the same
+/// exponential-decay-of-distance convention used for transfer scores
+/// elsewhere in this crate (see `spatial_pattern_db::PatternMatch`).
+fn overlap(c1: &PartitionCoord, c2: &PartitionCoord) -> f64 {
+    (-d_cat(c1, c2)).exp()
+}
+
+/// A candidate two-particle (double) excitation target pair, with its
+/// coupling weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoubleExcitation {
+    pub pair: (PartitionCoord, PartitionCoord),
+    pub weight: f64,
+}
+
+/// Enumerates two-particle excitation targets reachable by simultaneously
+/// moving the "particles" at source levels `h1`, `h2` one step each
+/// (candidates are drawn from `adjacent_coords`, so each target is one
+/// single excitation away from its source), with a coupling weight per
+/// pair. The weight uses one exchange term and is not averaged over spin
+/// assignments.
+///
+/// For a candidate target pair `(p1, p2)`, the weight is the
+/// antisymmetrized contraction `direct - exchange` over the categorical
+/// overlap kernel:
+/// - `direct` pairs `p1<->h1`, `p2<->h2` (the natural assignment);
+/// - `exchange` pairs `p1<->h2`, `p2<->h1` (the target indices swapped
+///   relative to the direct term).
+///
+/// Target pairs where `p1 == p2` are excluded: two particles can't both
+/// land on the same `(n, l, m, s)` state.
+pub fn double_excitation_coords(h1: &PartitionCoord, h2: &PartitionCoord) -> Vec<DoubleExcitation> {
+    let targets_1 = adjacent_coords(h1);
+    let targets_2 = adjacent_coords(h2);
+
+    let mut pairs = Vec::new();
+    for &p1 in &targets_1 {
+        for &p2 in &targets_2 {
+            if p1 == p2 {
+                continue;
+            }
+
+            let direct = overlap(&p1, h1) * overlap(&p2, h2);
+            let exchange = overlap(&p1, h2) * overlap(&p2, h1);
+            let weight = direct - exchange;
+
+            pairs.push(DoubleExcitation {
+                pair: (p1, p2),
+                weight,
+            });
+        }
+    }
+    pairs
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +274,77 @@ mod tests {
             assert!(coord.is_adjacent(a));
         }
     }
+
+    #[test]
+    fn test_quiet_softmax_bounds_and_self_dominance() {
+        let reference = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let states = PartitionCoord::iter_all(3).collect::<Vec<_>>();
+
+        let weights = quiet_softmax(&reference, &states, 2.0);
+
+        let total: f64 = weights.iter().map(|(_, w)| *w).sum();
+        assert!(total <= 1.0 + 1e-9);
+
+        for (_, w) in &weights {
+            assert!(*w >= 0.0 && *w <= 1.0);
+        }
+
+        // The reference coordinate itself (distance 0) should have the
+        // largest weight.
+        let (_, self_weight) = weights
+            .iter()
+            .find(|(c, _)| *c == reference)
+            .expect("reference coordinate present");
+        assert!(weights.iter().all(|(_, w)| w <= self_weight));
+    }
+
+    #[test]
+    fn test_quiet_softmax_decays_for_large_beta() {
+        let reference = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let states = PartitionCoord::iter_all(3)
+            .filter(|c| *c != reference)
+            .collect::<Vec<_>>();
+
+        let weights = quiet_softmax(&reference, &states, 50.0);
+        let total: f64 = weights.iter().map(|(_, w)| *w).sum();
+        assert!(total < 0.1);
+    }
+
+    #[test]
+    fn test_double_excitation_excludes_same_target_pair() {
+        let h1 = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let h2 = PartitionCoord::new(2, 1, 0, Spin::Down).unwrap();
+        let pairs = double_excitation_coords(&h1, &h2);
+        assert!(pairs.iter().all(|e| e.pair.0 != e.pair.1));
+    }
+
+    #[test]
+    fn test_double_excitation_targets_are_adjacent_to_their_source() {
+        let h1 = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let h2 = PartitionCoord::new(2, 1, -1, Spin::Down).unwrap();
+        let pairs = double_excitation_coords(&h1, &h2);
+        assert!(!pairs.is_empty());
+        for excitation in &pairs {
+            assert!(h1.is_adjacent(&excitation.pair.0));
+            assert!(h2.is_adjacent(&excitation.pair.1));
+        }
+    }
+
+    #[test]
+    fn test_double_excitation_weight_symmetric_under_source_and_target_swap() {
+        let h1 = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap();
+        let h2 = PartitionCoord::new(2, 1, -1, Spin::Down).unwrap();
+
+        let forward = double_excitation_coords(&h1, &h2);
+        let swapped = double_excitation_coords(&h2, &h1);
+
+        for excitation in &forward {
+            let (p1, p2) = excitation.pair;
+            let mirrored = swapped
+                .iter()
+                .find(|e| e.pair == (p2, p1))
+                .expect("swapping both sources and targets should produce the mirrored pair");
+            assert!((mirrored.weight - excitation.weight).abs() < 1e-9);
+        }
+    }
 }
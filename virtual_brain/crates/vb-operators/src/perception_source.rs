@@ -0,0 +1,208 @@
+//! Perception/Thought Input Sources: pluggable simulation drives.
+//!
+//! `consciousness_time_series` historically took a hand-written
+//! `&dyn Fn(f64) -> f64` perception profile and `evolve_mental_state` used a
+//! hardcoded `thought_input = 0.5`. `PerceptionSource` generalizes both into
+//! a single trait so analytic closures and learned models (see the
+//! `candle` submodule) interoperate behind the same call sites.
+
+use vb_core::types::MentalState;
+
+/// Paired perception/thought drive inputs sampled at simulation time `t`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DriveInputs {
+    /// Perception input rate.
+    pub perception: f64,
+    /// Thought input rate.
+    pub thought: f64,
+}
+
+/// Source of perception/thought drive inputs for `evolve_mental_state`.
+pub trait PerceptionSource {
+    /// Sample the drive inputs at time `t` given the current state.
+    fn sample(&mut self, t: f64, state: &MentalState) -> DriveInputs;
+}
+
+/// Adapts a plain analytic perception closure into a `PerceptionSource`,
+/// holding the thought input fixed (matching the historical default of
+/// `thought_input = 0.5`).
+pub struct ClosurePerceptionSource<F: Fn(f64) -> f64> {
+    perception_profile: F,
+    thought_input: f64,
+}
+
+impl<F: Fn(f64) -> f64> ClosurePerceptionSource<F> {
+    /// Create a source from a perception closure and a fixed thought input.
+    pub fn new(perception_profile: F, thought_input: f64) -> Self {
+        Self {
+            perception_profile,
+            thought_input,
+        }
+    }
+
+    /// Create a source using the historical default thought input (0.5).
+    pub fn with_default_thought(perception_profile: F) -> Self {
+        Self::new(perception_profile, 0.5)
+    }
+}
+
+impl<F: Fn(f64) -> f64> PerceptionSource for ClosurePerceptionSource<F> {
+    fn sample(&mut self, t: f64, _state: &MentalState) -> DriveInputs {
+        DriveInputs {
+            perception: (self.perception_profile)(t),
+            thought: self.thought_input,
+        }
+    }
+}
+
+/// Learned perception/thought sources backed by `candle`.
+///
+/// Gated behind the `candle` feature so the default build carries no ML
+/// dependency; enable it to drive simulations from a trained network
+/// instead of a hand-written closure.
+#[cfg(feature = "candle")]
+pub mod candle_source {
+    use super::{DriveInputs, MentalState, PerceptionSource};
+    use candle_core::{DType, Device, Tensor};
+    use candle_nn::{linear, Linear, Module, VarBuilder};
+    use std::path::{Path, PathBuf};
+
+    /// Small feed-forward network mapping
+    /// `(t, gamma, gamma_f, m, p_decay, t_decay) -> (perception, thought)`.
+    pub struct CandleModelSource {
+        fc1: Linear,
+        fc2: Linear,
+        out: Linear,
+        device: Device,
+    }
+
+    impl CandleModelSource {
+        const INPUT_DIM: usize = 6;
+        const HIDDEN_DIM: usize = 32;
+        const OUTPUT_DIM: usize = 2;
+
+        /// Load network weights from a `safetensors` file.
+        pub fn load(weights_path: impl AsRef<Path>) -> candle_core::Result<Self> {
+            let device = Device::Cpu;
+            let vb = unsafe {
+                VarBuilder::from_mmaped_safetensors(
+                    &[weights_path.as_ref().to_path_buf()],
+                    DType::F32,
+                    &device,
+                )?
+            };
+
+            let fc1 = linear(Self::INPUT_DIM, Self::HIDDEN_DIM, vb.pp("fc1"))?;
+            let fc2 = linear(Self::HIDDEN_DIM, Self::HIDDEN_DIM, vb.pp("fc2"))?;
+            let out = linear(Self::HIDDEN_DIM, Self::OUTPUT_DIM, vb.pp("out"))?;
+
+            Ok(Self {
+                fc1,
+                fc2,
+                out,
+                device,
+            })
+        }
+
+        fn forward(&self, input: &[f32; Self::INPUT_DIM]) -> candle_core::Result<(f64, f64)> {
+            let x = Tensor::from_slice(input, (1, Self::INPUT_DIM), &self.device)?;
+            let x = self.fc1.forward(&x)?.relu()?;
+            let x = self.fc2.forward(&x)?.relu()?;
+            let y = self.out.forward(&x)?;
+            let values: Vec<f32> = y.flatten_all()?.to_vec1()?;
+            Ok((values[0] as f64, values[1] as f64))
+        }
+    }
+
+    impl PerceptionSource for CandleModelSource {
+        fn sample(&mut self, t: f64, state: &MentalState) -> DriveInputs {
+            let input = [
+                t as f32,
+                state.gamma as f32,
+                state.gamma_f as f32,
+                state.m as f32,
+                state.p_decay as f32,
+                state.t_decay as f32,
+            ];
+
+            match self.forward(&input) {
+                Ok((perception, thought)) => DriveInputs {
+                    perception: perception.clamp(0.0, 1.0),
+                    thought: thought.clamp(0.0, 1.0),
+                },
+                Err(err) => {
+                    // A neutral (0.5, 0.5) drive is otherwise indistinguishable
+                    // from a legitimately neutral model output, so a bad
+                    // weights file or shape mismatch would degrade silently;
+                    // surface it on stderr instead of swallowing it outright.
+                    eprintln!("[WARN] CandleModelSource::forward failed, falling back to neutral drive: {err}");
+                    DriveInputs {
+                        perception: 0.5,
+                        thought: 0.5,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fetch a weights file from the Hugging Face Hub, returning its
+    /// local cache path.
+    pub fn fetch_from_hub(repo: &str, filename: &str) -> anyhow::Result<PathBuf> {
+        use hf_hub::api::sync::Api;
+
+        let api = Api::new()?;
+        let repo = api.model(repo.to_string());
+        Ok(repo.get(filename)?)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// A source whose `fc2` layer expects the wrong input width, so
+        /// `forward` fails mid-pass with a shape-mismatch error instead of
+        /// at `load` time — standing in for a corrupt/mismatched weights
+        /// file without needing one on disk.
+        fn mismatched_source() -> CandleModelSource {
+            let device = Device::Cpu;
+            let vb = VarBuilder::zeros(DType::F32, &device);
+            let fc1 = linear(CandleModelSource::INPUT_DIM, CandleModelSource::HIDDEN_DIM, vb.pp("fc1"))
+                .unwrap();
+            let fc2 = linear(
+                CandleModelSource::HIDDEN_DIM + 1,
+                CandleModelSource::HIDDEN_DIM,
+                vb.pp("fc2"),
+            )
+            .unwrap();
+            let out = linear(CandleModelSource::HIDDEN_DIM, CandleModelSource::OUTPUT_DIM, vb.pp("out"))
+                .unwrap();
+            CandleModelSource { fc1, fc2, out, device }
+        }
+
+        #[test]
+        fn test_sample_falls_back_to_neutral_drive_on_forward_error() {
+            let mut source = mismatched_source();
+            let state = MentalState::default();
+
+            let inputs = source.sample(0.0, &state);
+
+            assert!((inputs.perception - 0.5).abs() < 1e-10);
+            assert!((inputs.thought - 0.5).abs() < 1e-10);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closure_source_samples_perception() {
+        let mut source = ClosurePerceptionSource::with_default_thought(|t| t * 2.0);
+        let state = MentalState::default();
+
+        let inputs = source.sample(0.25, &state);
+        assert!((inputs.perception - 0.5).abs() < 1e-10);
+        assert!((inputs.thought - 0.5).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,364 @@
+//! Phase-Amplitude Coupling: Tort modulation-index quantification of
+//! cross-frequency coupling between a low-frequency phase band and a
+//! high-frequency amplitude band, as used for `Neural` oscillations'
+//! `PhaseCoupling` entries and `synchronization_index`.
+//!
+//! Band-pass filtering and the Hilbert transform are both done via the
+//! same FFT machinery `spectral` uses, rather than a time-domain biquad,
+//! since this crate already has no filter-design code beyond the FFT.
+
+use crate::spectral::fft;
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Default number of phase bins used by Tort et al.'s modulation index.
+pub const DEFAULT_PAC_BINS: usize = 18;
+
+/// A named frequency range to analyze, e.g. `("theta", (4.0, 8.0))`.
+#[derive(Debug, Clone)]
+pub struct FrequencyBandRange {
+    pub name: String,
+    pub range: (f64, f64),
+}
+
+/// Phase coupling between a low-frequency phase band and a
+/// high-frequency amplitude band, mirroring the `PhaseCoupling` record
+/// carried on `Neural` oscillations.
+#[derive(Debug, Clone)]
+pub struct PhaseCoupling {
+    pub low_frequency_band: String,
+    pub high_frequency_band: String,
+    pub coupling_strength: f64,
+    pub coupling_phase: f64,
+}
+
+fn next_power_of_two(n: usize) -> usize {
+    n.max(1).next_power_of_two()
+}
+
+fn ifft(data: &mut Vec<Complex64>) {
+    let n = data.len();
+    for x in data.iter_mut() {
+        *x = x.conj();
+    }
+    fft(data);
+    for x in data.iter_mut() {
+        *x = x.conj() / n as f64;
+    }
+}
+
+/// Frequency (Hz) represented by FFT bin `k` of an `n`-point transform
+/// sampled at `sampling_rate`, folding bins past the Nyquist frequency
+/// back onto their negative-frequency magnitude.
+fn bin_frequency(k: usize, n: usize, sampling_rate: f64) -> f64 {
+    let folded = if k <= n / 2 { k } else { n - k };
+    folded as f64 * sampling_rate / n as f64
+}
+
+/// Zero-pad `signal` to the next power of two, FFT it, zero every bin
+/// whose frequency falls outside `band`, and inverse-FFT back, returning
+/// the real band-passed signal truncated to the original length.
+fn bandpass_filter(signal: &[f64], sampling_rate: f64, band: (f64, f64)) -> Vec<f64> {
+    let original_len = signal.len();
+    let padded_len = next_power_of_two(original_len);
+    let mut spectrum: Vec<Complex64> = signal
+        .iter()
+        .map(|&x| Complex64::new(x, 0.0))
+        .chain(std::iter::repeat(Complex64::new(0.0, 0.0)))
+        .take(padded_len)
+        .collect();
+
+    fft(&mut spectrum);
+    for (k, bin) in spectrum.iter_mut().enumerate() {
+        let freq = bin_frequency(k, padded_len, sampling_rate);
+        if freq < band.0 || freq > band.1 {
+            *bin = Complex64::new(0.0, 0.0);
+        }
+    }
+    ifft(&mut spectrum);
+
+    spectrum.iter().take(original_len).map(|c| c.re).collect()
+}
+
+/// Analytic signal of a real `signal` via the FFT-domain Hilbert
+/// transform: zero negative-frequency bins, double positive-frequency
+/// bins (leaving DC and Nyquist alone), and inverse-FFT. The resulting
+/// complex signal's magnitude is the instantaneous amplitude envelope
+/// and its argument is the instantaneous phase.
+fn analytic_signal(signal: &[f64]) -> Vec<Complex64> {
+    let original_len = signal.len();
+    let padded_len = next_power_of_two(original_len);
+    let mut spectrum: Vec<Complex64> = signal
+        .iter()
+        .map(|&x| Complex64::new(x, 0.0))
+        .chain(std::iter::repeat(Complex64::new(0.0, 0.0)))
+        .take(padded_len)
+        .collect();
+
+    fft(&mut spectrum);
+
+    let nyquist = padded_len / 2;
+    for (k, bin) in spectrum.iter_mut().enumerate() {
+        if k == 0 || k == nyquist {
+            // DC and Nyquist bins are left as-is.
+        } else if k < nyquist {
+            *bin *= 2.0;
+        } else {
+            *bin = Complex64::new(0.0, 0.0);
+        }
+    }
+
+    ifft(&mut spectrum);
+    spectrum.into_iter().take(original_len).collect()
+}
+
+fn shannon_entropy(probabilities: &[f64]) -> f64 {
+    -probabilities
+        .iter()
+        .filter(|&&p| p > 0.0)
+        .map(|&p| p * p.ln())
+        .sum::<f64>()
+}
+
+/// Tort modulation index between a `phase_band` (low frequency) and an
+/// `amplitude_band` (high frequency): bins the low-band instantaneous
+/// phase into `n_bins` equal bins, averages the high-band amplitude
+/// envelope within each bin, and returns `(coupling_strength,
+/// coupling_phase)` where `coupling_strength` is the normalized
+/// divergence of that binned-amplitude distribution from uniform, and
+/// `coupling_phase` is the center of the bin with maximum mean
+/// amplitude.
+///
+/// Returns `(0.0, 0.0)` if the signal is too short to filter/bin, or if
+/// the amplitude envelope is flat (no coupling to detect).
+pub fn modulation_index(
+    signal: &[f64],
+    sampling_rate: f64,
+    phase_band: (f64, f64),
+    amplitude_band: (f64, f64),
+    n_bins: usize,
+) -> (f64, f64) {
+    if signal.len() < 2 || n_bins == 0 {
+        return (0.0, 0.0);
+    }
+
+    let low_filtered = bandpass_filter(signal, sampling_rate, phase_band);
+    let high_filtered = bandpass_filter(signal, sampling_rate, amplitude_band);
+
+    let phase = analytic_signal(&low_filtered);
+    let envelope = analytic_signal(&high_filtered);
+
+    let mut bin_sums = vec![0.0_f64; n_bins];
+    let mut bin_counts = vec![0usize; n_bins];
+    let bin_width = 2.0 * PI / n_bins as f64;
+
+    for (p, a) in phase.iter().zip(envelope.iter()) {
+        let phi = p.arg();
+        let amplitude = a.norm();
+        let mut bin_idx = ((phi + PI) / bin_width).floor() as isize;
+        bin_idx = bin_idx.clamp(0, n_bins as isize - 1);
+        bin_sums[bin_idx as usize] += amplitude;
+        bin_counts[bin_idx as usize] += 1;
+    }
+
+    let mean_amplitudes: Vec<f64> = bin_sums
+        .iter()
+        .zip(bin_counts.iter())
+        .map(|(&sum, &count)| if count > 0 { sum / count as f64 } else { 0.0 })
+        .collect();
+
+    let total: f64 = mean_amplitudes.iter().sum();
+    if total <= 0.0 {
+        return (0.0, 0.0);
+    }
+
+    let probabilities: Vec<f64> = mean_amplitudes.iter().map(|&m| m / total).collect();
+    let entropy = shannon_entropy(&probabilities);
+    let max_entropy = (n_bins as f64).ln();
+    let coupling_strength = if max_entropy > 0.0 {
+        ((max_entropy - entropy) / max_entropy).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let (max_bin, _) = mean_amplitudes
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .unwrap();
+    let coupling_phase = -PI + (max_bin as f64 + 0.5) * bin_width;
+
+    (coupling_strength, coupling_phase)
+}
+
+/// Computes a [`PhaseCoupling`] record for one (phase, amplitude) band
+/// pair.
+pub fn phase_amplitude_coupling(
+    signal: &[f64],
+    sampling_rate: f64,
+    phase_band: &FrequencyBandRange,
+    amplitude_band: &FrequencyBandRange,
+    n_bins: usize,
+) -> PhaseCoupling {
+    let (coupling_strength, coupling_phase) = modulation_index(
+        signal,
+        sampling_rate,
+        phase_band.range,
+        amplitude_band.range,
+        n_bins,
+    );
+    PhaseCoupling {
+        low_frequency_band: phase_band.name.clone(),
+        high_frequency_band: amplitude_band.name.clone(),
+        coupling_strength,
+        coupling_phase,
+    }
+}
+
+/// Computes [`PhaseCoupling`] for every band pair where `bands[i]`'s
+/// range lies below `bands[j]`'s range (`i < j`, bands given in
+/// ascending-frequency order), alongside a `synchronization_index`
+/// equal to the mean `coupling_strength` across all pairs (`0.0` if
+/// fewer than two bands are supplied).
+pub fn cross_frequency_coupling_analysis(
+    signal: &[f64],
+    sampling_rate: f64,
+    bands: &[FrequencyBandRange],
+    n_bins: usize,
+) -> (Vec<PhaseCoupling>, f64) {
+    let mut pairs = Vec::new();
+    for i in 0..bands.len() {
+        for j in (i + 1)..bands.len() {
+            pairs.push(phase_amplitude_coupling(
+                signal,
+                sampling_rate,
+                &bands[i],
+                &bands[j],
+                n_bins,
+            ));
+        }
+    }
+
+    let synchronization_index = if pairs.is_empty() {
+        0.0
+    } else {
+        pairs.iter().map(|p| p.coupling_strength).sum::<f64>() / pairs.len() as f64
+    };
+
+    (pairs, synchronization_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coupled_signal(n: usize, sampling_rate: f64, low_freq: f64, high_freq: f64) -> Vec<f64> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64 / sampling_rate;
+                let low_phase = 2.0 * PI * low_freq * t;
+                // Amplitude of the high-frequency carrier is modulated by
+                // the low-frequency phase, producing genuine PAC.
+                let envelope = 1.0 + low_phase.sin();
+                low_phase.sin() + envelope * (2.0 * PI * high_freq * t).sin()
+            })
+            .collect()
+    }
+
+    fn uncoupled_signal(n: usize, sampling_rate: f64, low_freq: f64, high_freq: f64) -> Vec<f64> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64 / sampling_rate;
+                (2.0 * PI * low_freq * t).sin() + (2.0 * PI * high_freq * t).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_modulation_index_higher_for_coupled_than_uncoupled_signal() {
+        let fs = 512.0;
+        let n = 1024;
+        let coupled = coupled_signal(n, fs, 5.0, 80.0);
+        let uncoupled = uncoupled_signal(n, fs, 5.0, 80.0);
+
+        let (coupled_strength, _) = modulation_index(&coupled, fs, (2.0, 8.0), (60.0, 100.0), DEFAULT_PAC_BINS);
+        let (uncoupled_strength, _) =
+            modulation_index(&uncoupled, fs, (2.0, 8.0), (60.0, 100.0), DEFAULT_PAC_BINS);
+
+        assert!(coupled_strength > uncoupled_strength);
+    }
+
+    #[test]
+    fn test_modulation_index_is_bounded_in_unit_interval() {
+        let fs = 512.0;
+        let signal = coupled_signal(1024, fs, 5.0, 80.0);
+        let (strength, _) = modulation_index(&signal, fs, (2.0, 8.0), (60.0, 100.0), DEFAULT_PAC_BINS);
+        assert!((0.0..=1.0).contains(&strength));
+    }
+
+    #[test]
+    fn test_modulation_index_zero_for_flat_envelope() {
+        let flat = vec![0.0; 256];
+        let (strength, phase) = modulation_index(&flat, 256.0, (2.0, 8.0), (60.0, 100.0), DEFAULT_PAC_BINS);
+        assert_eq!(strength, 0.0);
+        assert_eq!(phase, 0.0);
+    }
+
+    #[test]
+    fn test_modulation_index_handles_too_short_signal() {
+        let (strength, phase) = modulation_index(&[1.0], 100.0, (2.0, 8.0), (60.0, 100.0), DEFAULT_PAC_BINS);
+        assert_eq!(strength, 0.0);
+        assert_eq!(phase, 0.0);
+    }
+
+    #[test]
+    fn test_phase_amplitude_coupling_carries_band_names() {
+        let fs = 512.0;
+        let signal = coupled_signal(1024, fs, 5.0, 80.0);
+        let low = FrequencyBandRange {
+            name: "theta".to_string(),
+            range: (2.0, 8.0),
+        };
+        let high = FrequencyBandRange {
+            name: "gamma".to_string(),
+            range: (60.0, 100.0),
+        };
+        let coupling = phase_amplitude_coupling(&signal, fs, &low, &high, DEFAULT_PAC_BINS);
+        assert_eq!(coupling.low_frequency_band, "theta");
+        assert_eq!(coupling.high_frequency_band, "gamma");
+    }
+
+    #[test]
+    fn test_cross_frequency_coupling_analysis_averages_into_synchronization_index() {
+        let fs = 512.0;
+        let signal = coupled_signal(1024, fs, 5.0, 80.0);
+        let bands = vec![
+            FrequencyBandRange {
+                name: "theta".to_string(),
+                range: (2.0, 8.0),
+            },
+            FrequencyBandRange {
+                name: "gamma".to_string(),
+                range: (60.0, 100.0),
+            },
+        ];
+        let (pairs, synchronization_index) =
+            cross_frequency_coupling_analysis(&signal, fs, &bands, DEFAULT_PAC_BINS);
+        assert_eq!(pairs.len(), 1);
+        assert!((synchronization_index - pairs[0].coupling_strength).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cross_frequency_coupling_analysis_zero_for_single_band() {
+        let fs = 512.0;
+        let signal = coupled_signal(256, fs, 5.0, 80.0);
+        let bands = vec![FrequencyBandRange {
+            name: "theta".to_string(),
+            range: (2.0, 8.0),
+        }];
+        let (pairs, synchronization_index) =
+            cross_frequency_coupling_analysis(&signal, fs, &bands, DEFAULT_PAC_BINS);
+        assert!(pairs.is_empty());
+        assert_eq!(synchronization_index, 0.0);
+    }
+}
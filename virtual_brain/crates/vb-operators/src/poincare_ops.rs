@@ -5,8 +5,25 @@
 use serde::{Deserialize, Serialize};
 use vb_core::types::{MentalState, SCoord};
 
+use crate::diis::{DiisAccelerator, DEFAULT_DIIS_DAMPING, DEFAULT_DIIS_HISTORY};
+
 const EPSILON: f64 = 1e-10;
 
+fn scoord_to_vec(state: &SCoord) -> Vec<f64> {
+    vec![state.sk, state.st, state.se]
+}
+
+/// Builds an `SCoord` from a DIIS-extrapolated point, clamped into
+/// `[0,1]^3`. The extrapolation coefficients are solved only to sum to 1,
+/// not to keep each individually in `[0,1]`, so the raw point can land
+/// outside the domain every other `SCoord`-producing path in this crate
+/// maintains as an invariant; clamping here keeps it safe to hand to
+/// caller-supplied constraint closures that may assume that invariant
+/// (e.g. a `log` or `sqrt` of a coordinate).
+fn vec_to_scoord(v: &[f64]) -> SCoord {
+    SCoord::new_unchecked(v[0].clamp(0.0, 1.0), v[1].clamp(0.0, 1.0), v[2].clamp(0.0, 1.0))
+}
+
 /// Result of a completion operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletionResult {
@@ -23,12 +40,21 @@ pub struct CompletionResult {
 }
 
 /// COMPLETE operator: Satisfy constraints through gradient descent.
+///
+/// When `use_diis` is set, each step's iterate/constraint-gradient pair is
+/// fed into a [`DiisAccelerator`]; the gradient step is then taken from the
+/// accelerator's extrapolated point rather than the raw current state, at
+/// a rate damped by [`DEFAULT_DIIS_DAMPING`]. On the first iteration (a
+/// single history entry) and whenever the DIIS system turns out singular,
+/// this falls back to (and, after a singularity, resets to) the plain
+/// gradient step.
 pub fn complete<F>(
     partial_state: &SCoord,
     constraints: &[F],
     max_iterations: usize,
     tolerance: f64,
     learning_rate: f64,
+    use_diis: bool,
 ) -> CompletionResult
 where
     F: Fn(&SCoord) -> f64,
@@ -36,6 +62,7 @@ where
     let mut state = *partial_state;
     let mut trajectory = vec![state];
     let mut violations = Vec::new();
+    let mut diis = DiisAccelerator::new(DEFAULT_DIIS_HISTORY);
 
     for iter in 0..max_iterations {
         // Compute total constraint violation
@@ -55,12 +82,27 @@ where
         // Compute gradient of constraint violation
         let grad = compute_constraint_gradient(&state, constraints, 1e-6);
 
-        // Update state
-        state = state.update(
-            -learning_rate * grad[0],
-            -learning_rate * grad[1],
-            -learning_rate * grad[2],
-        );
+        state = if use_diis {
+            diis.push(scoord_to_vec(&state), grad.to_vec());
+            match diis.extrapolate() {
+                Some(p_star) => {
+                    let p_star = vec_to_scoord(&p_star);
+                    let grad_at_star = compute_constraint_gradient(&p_star, constraints, 1e-6);
+                    let damping = if diis.len() == 1 { 1.0 } else { DEFAULT_DIIS_DAMPING };
+                    p_star.update(
+                        -learning_rate * damping * grad_at_star[0],
+                        -learning_rate * damping * grad_at_star[1],
+                        -learning_rate * damping * grad_at_star[2],
+                    )
+                }
+                None => {
+                    diis.reset();
+                    state.update(-learning_rate * grad[0], -learning_rate * grad[1], -learning_rate * grad[2])
+                }
+            }
+        } else {
+            state.update(-learning_rate * grad[0], -learning_rate * grad[1], -learning_rate * grad[2])
+        };
 
         trajectory.push(state);
     }
@@ -176,6 +218,195 @@ pub fn consciousness_constraint(target_c: f64, tolerance: f64) -> impl Fn(f64) -
     }
 }
 
+/// Direct-collocation trajectory optimizer for coupling schedules.
+///
+/// Computes an optimal time-varying coupling schedule `K(t)` offline, as an
+/// alternative to the greedy per-step `compute_consciousness` adjustment.
+/// `PoincareComputer::run_simulation` takes no coupling schedule, so the
+/// plan is not replayed through it.
+pub mod trajectory_optimizer {
+    /// Reduced order-parameter ODE used as the collocation dynamics model:
+    /// `dz/dt = f(z, u) = u*z*(1-z) - z/tau_r`.
+    ///
+    /// `z` is a coherence/consciousness proxy in `[0, 1]` — logistic growth
+    /// driven by the coupling control `u`, relaxing back toward 0 at rate
+    /// `1/tau_r` absent drive. This stands in for the full N-oscillator
+    /// Kuramoto order parameter ODE, cheap enough to differentiate
+    /// thousands of times during gradient descent.
+    fn order_parameter_dynamics(z: f64, u: f64, tau_r: f64) -> f64 {
+        u * z * (1.0 - z) - z / tau_r
+    }
+
+    /// Optimized coupling schedule and its predicted coherence trajectory.
+    #[derive(Debug, Clone)]
+    pub struct TrajectoryPlan {
+        /// Knot times `t_k`.
+        pub times: Vec<f64>,
+        /// Optimized control schedule `K(t_k) = u_k`.
+        pub control_schedule: Vec<f64>,
+        /// Predicted coherence/consciousness proxy `z_k` under the
+        /// optimized schedule.
+        pub predicted_trajectory: Vec<f64>,
+        /// Total cost at each gradient-descent iteration.
+        pub cost_history: Vec<f64>,
+    }
+
+    /// Direct-collocation optimizer over a fixed horizon, discretized into
+    /// `n_knots` knot points `t_k = k * dt`.
+    #[derive(Debug, Clone)]
+    pub struct TrajectoryOptimizer {
+        /// Number of knot points (>= 2).
+        pub n_knots: usize,
+        /// Horizon duration in seconds.
+        pub horizon: f64,
+        /// Relaxation time of the reduced order-parameter ODE.
+        pub tau_r: f64,
+        /// Minimum admissible coupling.
+        pub k_min: f64,
+        /// Maximum admissible coupling.
+        pub k_max: f64,
+        /// Control-effort cost weight.
+        pub w_u: f64,
+        /// Terminal-tracking cost weight.
+        pub w_target: f64,
+        /// Projected-gradient-descent step size.
+        pub learning_rate: f64,
+        /// Number of gradient-descent iterations.
+        pub max_iterations: usize,
+    }
+
+    impl TrajectoryOptimizer {
+        /// Create a new trajectory optimizer.
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            n_knots: usize,
+            horizon: f64,
+            tau_r: f64,
+            k_min: f64,
+            k_max: f64,
+            w_u: f64,
+            w_target: f64,
+            learning_rate: f64,
+            max_iterations: usize,
+        ) -> Self {
+            Self {
+                n_knots: n_knots.max(2),
+                horizon,
+                tau_r,
+                k_min,
+                k_max,
+                w_u,
+                w_target,
+                learning_rate,
+                max_iterations,
+            }
+        }
+
+        fn dt(&self) -> f64 {
+            self.horizon / (self.n_knots - 1) as f64
+        }
+
+        /// Roll out the knot trajectory `z_k` for a given control schedule
+        /// `u_k`, solving the trapezoidal defect
+        /// `z_{k+1} - z_k = (dt/2)(f(z_k,u_k) + f(z_{k+1},u_{k+1}))`
+        /// at each step by fixed-point iteration (`f` is smooth and bounded
+        /// on `[0, 1]`, so a handful of iterations is enough to converge).
+        fn rollout(&self, z0: f64, controls: &[f64]) -> Vec<f64> {
+            let dt = self.dt();
+            let mut z = vec![z0; self.n_knots];
+
+            for k in 0..self.n_knots - 1 {
+                let f_k = order_parameter_dynamics(z[k], controls[k], self.tau_r);
+                let mut z_next = z[k] + dt * f_k;
+                for _ in 0..4 {
+                    let f_next = order_parameter_dynamics(z_next, controls[k + 1], self.tau_r);
+                    z_next = z[k] + (dt / 2.0) * (f_k + f_next);
+                }
+                z[k + 1] = z_next.clamp(0.0, 1.0);
+            }
+
+            z
+        }
+
+        /// Cost `Σ w_u*u_k^2 + w_T*(c_N - target)^2`.
+        fn cost(&self, z0: f64, controls: &[f64], target: f64) -> f64 {
+            let z = self.rollout(z0, controls);
+            let u_cost: f64 = controls.iter().map(|u| self.w_u * u * u).sum();
+            let terminal = *z.last().unwrap();
+            let t_cost = self.w_target * (terminal - target).powi(2);
+            u_cost + t_cost
+        }
+
+        /// Optimize the coupling schedule via projected gradient descent
+        /// with finite-difference gradients, returning the optimized
+        /// schedule plus predicted trajectory.
+        pub fn optimize(&self, z0: f64, target: f64) -> TrajectoryPlan {
+            let dt = self.dt();
+            let times: Vec<f64> = (0..self.n_knots).map(|k| k as f64 * dt).collect();
+            let mut controls = vec![(self.k_min + self.k_max) / 2.0; self.n_knots];
+            let mut cost_history = Vec::with_capacity(self.max_iterations + 1);
+            let h = 1e-4;
+
+            for _ in 0..self.max_iterations {
+                let base_cost = self.cost(z0, &controls, target);
+                cost_history.push(base_cost);
+
+                let mut grad = vec![0.0; self.n_knots];
+                for k in 0..self.n_knots {
+                    let mut perturbed = controls.clone();
+                    perturbed[k] += h;
+                    let c_plus = self.cost(z0, &perturbed, target);
+                    grad[k] = (c_plus - base_cost) / h;
+                }
+
+                for (u, g) in controls.iter_mut().zip(grad.iter()) {
+                    *u = (*u - self.learning_rate * g).clamp(self.k_min, self.k_max);
+                }
+            }
+
+            cost_history.push(self.cost(z0, &controls, target));
+            let predicted_trajectory = self.rollout(z0, &controls);
+
+            TrajectoryPlan {
+                times,
+                control_schedule: controls,
+                predicted_trajectory,
+                cost_history,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_optimize_drives_trajectory_toward_target() {
+            let optimizer = TrajectoryOptimizer::new(10, 1.0, 2.0, 0.0, 5.0, 1e-3, 10.0, 0.05, 200);
+            let plan = optimizer.optimize(0.1, 0.8);
+
+            assert_eq!(plan.times.len(), 10);
+            assert_eq!(plan.control_schedule.len(), 10);
+            assert_eq!(plan.predicted_trajectory.len(), 10);
+            for u in &plan.control_schedule {
+                assert!(*u >= 0.0 && *u <= 5.0);
+            }
+
+            // Cost should not increase over the course of optimization.
+            assert!(plan.cost_history.last().unwrap() <= &plan.cost_history[0]);
+        }
+
+        #[test]
+        fn test_rollout_stays_within_unit_interval() {
+            let optimizer = TrajectoryOptimizer::new(20, 2.0, 1.0, 0.0, 3.0, 1e-3, 5.0, 0.05, 50);
+            let z = optimizer.rollout(0.2, &vec![2.0; 20]);
+            for zk in z {
+                assert!((0.0..=1.0).contains(&zk));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,12 +451,56 @@ mod tests {
             1000,
             0.01,
             0.1,
+            false,
         );
 
         assert!(result.success);
         assert!((result.final_state.sk - 0.5).abs() < 0.05);
     }
 
+    #[test]
+    fn test_complete_with_diis_converges_in_fewer_iterations() {
+        let initial = SCoord::new(0.1, 0.1, 0.1).unwrap();
+        let constraints: Vec<Box<dyn Fn(&SCoord) -> f64>> = vec![
+            Box::new(|s: &SCoord| s.sk - 0.5),
+            Box::new(|s: &SCoord| s.st - 0.5),
+            Box::new(|s: &SCoord| s.se - 0.5),
+        ];
+        let boxed_refs: Vec<_> = constraints.iter().map(|c| c.as_ref()).collect();
+
+        let plain = complete(&initial, &boxed_refs, 1000, 0.01, 0.1, false);
+        let accelerated = complete(&initial, &boxed_refs, 1000, 0.01, 0.1, true);
+
+        assert!(plain.success);
+        assert!(accelerated.success);
+        assert!(accelerated.iterations <= plain.iterations);
+    }
+
+    #[test]
+    fn test_complete_with_diis_never_evaluates_constraint_outside_unit_cube() {
+        // A constraint that would produce NaN if handed an out-of-[0,1]
+        // coordinate (sqrt of a negative). DIIS extrapolation is only
+        // guaranteed to sum its coefficients to 1, not to keep each
+        // individually in [0,1], so without clamping this would be able
+        // to see sk/st/se < 0 and poison the trajectory with NaN.
+        let initial = SCoord::new(0.05, 0.9, 0.5).unwrap();
+        let constraints: Vec<Box<dyn Fn(&SCoord) -> f64>> = vec![
+            Box::new(|s: &SCoord| s.sk.sqrt() - 0.5),
+            Box::new(|s: &SCoord| s.st.sqrt() - 0.5),
+            Box::new(|s: &SCoord| s.se.sqrt() - 0.5),
+        ];
+        let boxed_refs: Vec<_> = constraints.iter().map(|c| c.as_ref()).collect();
+
+        let result = complete(&initial, &boxed_refs, 200, 0.01, 0.2, true);
+
+        assert!(result.final_state.sk.is_finite());
+        assert!(result.final_state.st.is_finite());
+        assert!(result.final_state.se.is_finite());
+        for violation in &result.constraint_violations {
+            assert!(violation.is_finite());
+        }
+    }
+
     fn complete_with_refs<F>(
         partial_state: &SCoord,
         constraints: &[&F],
@@ -250,6 +525,7 @@ mod tests {
             max_iterations,
             tolerance,
             learning_rate,
+            false,
         )
     }
 }
@@ -0,0 +1,305 @@
+//! Sampling Schedule: config-driven inclusion/exclusion epochs that define
+//! which stretches of a signal are admissible for constrained stochastic
+//! sampling, replacing an opaque hard-coded window with a loadable,
+//! validated config.
+//!
+//! A [`SamplingSchedule`] lists per-signal epochs (time ranges, in either
+//! sample-index or second units) tagged as inclusion or exclusion, plus a
+//! `default_visibility` baseline. [`SamplingSchedule::admissible_mask`]
+//! resolves overlaps deterministically: inclusion epochs are applied
+//! first, then exclusion epochs, so an exclusion always wins over an
+//! inclusion that covers the same instant, no matter the declaration
+//! order. An epoch's `end` of `None` means "visible until" the end of the
+//! signal (open-ended).
+//!
+//! This crate has no YAML dependency, so [`SamplingSchedule::to_yaml`] and
+//! [`SamplingSchedule::from_yaml`] implement a small hand-rolled subset of
+//! YAML scoped to exactly this schema (flat `key: value` lines plus one
+//! `epochs:` block list) rather than a general-purpose parser.
+
+use serde::{Deserialize, Serialize};
+use vb_core::error::ScheduleError;
+
+/// The unit epoch boundaries are expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeUnit {
+    SampleIndex,
+    Seconds,
+}
+
+/// The schedule's baseline visibility before any epoch is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Visibility {
+    Visible,
+    Hidden,
+}
+
+/// Whether an epoch marks its range visible (inclusion) or masked out
+/// (exclusion).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EpochKind {
+    Inclusion,
+    Exclusion,
+}
+
+/// A single time range, in the schedule's [`TimeUnit`]. `end == None` means
+/// open-ended: visible (or excluded) from `start` through the rest of the
+/// signal.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Epoch {
+    pub kind: EpochKind,
+    pub start: f64,
+    pub end: Option<f64>,
+}
+
+impl Epoch {
+    fn contains(&self, t: f64) -> bool {
+        t >= self.start && self.end.map_or(true, |end| t < end)
+    }
+}
+
+/// A signal's inclusion/exclusion windows for constrained stochastic
+/// sampling, loadable from a YAML config so the admissible-time mask is no
+/// longer hard-coded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SamplingSchedule {
+    pub signal_id: String,
+    pub unit: TimeUnit,
+    pub default_visibility: Visibility,
+    pub epochs: Vec<Epoch>,
+}
+
+impl SamplingSchedule {
+    /// Rejects any epoch with an inverted (`end <= start`) or empty
+    /// (`end == start`) range. Open-ended epochs (`end == None`) always
+    /// pass.
+    pub fn validate(&self) -> Result<(), ScheduleError> {
+        for epoch in &self.epochs {
+            if let Some(end) = epoch.end {
+                if end <= epoch.start {
+                    return Err(ScheduleError::InvertedRange { start: epoch.start, end });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether instant `t` is admissible: starts from `default_visibility`,
+    /// applies every inclusion epoch, then every exclusion epoch, so
+    /// exclusions always win over inclusions on overlap regardless of the
+    /// order the epochs were declared in.
+    pub fn is_admissible(&self, t: f64) -> bool {
+        let mut visible = matches!(self.default_visibility, Visibility::Visible);
+        for epoch in self.epochs.iter().filter(|e| e.kind == EpochKind::Inclusion) {
+            if epoch.contains(t) {
+                visible = true;
+            }
+        }
+        for epoch in self.epochs.iter().filter(|e| e.kind == EpochKind::Exclusion) {
+            if epoch.contains(t) {
+                visible = false;
+            }
+        }
+        visible
+    }
+
+    /// The admissible-time mask for `times`, for feeding into the
+    /// constrained random walk so meta-information extraction only runs
+    /// over in-window data.
+    pub fn admissible_mask(&self, times: &[f64]) -> Vec<bool> {
+        times.iter().map(|&t| self.is_admissible(t)).collect()
+    }
+
+    /// Serializes to the hand-rolled YAML subset described in the module
+    /// doc comment.
+    pub fn to_yaml(&self) -> String {
+        let unit = match self.unit {
+            TimeUnit::SampleIndex => "sample_index",
+            TimeUnit::Seconds => "seconds",
+        };
+        let default_visibility = match self.default_visibility {
+            Visibility::Visible => "visible",
+            Visibility::Hidden => "hidden",
+        };
+
+        let mut out = format!(
+            "signal_id: {}\nunit: {unit}\ndefault_visibility: {default_visibility}\nepochs:\n",
+            self.signal_id
+        );
+        for epoch in &self.epochs {
+            let kind = match epoch.kind {
+                EpochKind::Inclusion => "inclusion",
+                EpochKind::Exclusion => "exclusion",
+            };
+            let end = match epoch.end {
+                Some(end) => end.to_string(),
+                None => "null".to_string(),
+            };
+            out.push_str(&format!("  - kind: {kind}\n    start: {}\n    end: {end}\n", epoch.start));
+        }
+        out
+    }
+
+    /// Parses the hand-rolled YAML subset produced by [`Self::to_yaml`],
+    /// then validates the result.
+    pub fn from_yaml(yaml: &str) -> Result<Self, ScheduleError> {
+        let mut signal_id = None;
+        let mut unit = None;
+        let mut default_visibility = None;
+        let mut epochs = Vec::new();
+
+        let mut pending: Option<(Option<EpochKind>, Option<f64>, Option<Option<f64>>)> = None;
+        let flush = |pending: &mut Option<(Option<EpochKind>, Option<f64>, Option<Option<f64>>)>,
+                     epochs: &mut Vec<Epoch>|
+         -> Result<(), ScheduleError> {
+            if let Some((kind, start, end)) = pending.take() {
+                let kind = kind.ok_or_else(|| ScheduleError::Malformed("epoch missing kind".into()))?;
+                let start = start.ok_or_else(|| ScheduleError::Malformed("epoch missing start".into()))?;
+                let end = end.ok_or_else(|| ScheduleError::Malformed("epoch missing end".into()))?;
+                epochs.push(Epoch { kind, start, end });
+            }
+            Ok(())
+        };
+
+        for raw_line in yaml.lines() {
+            let line = raw_line.trim_end();
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(rest) = line.strip_prefix("  - kind:") {
+                flush(&mut pending, &mut epochs)?;
+                let kind = match rest.trim() {
+                    "inclusion" => EpochKind::Inclusion,
+                    "exclusion" => EpochKind::Exclusion,
+                    other => return Err(ScheduleError::Malformed(format!("unknown epoch kind: {other}"))),
+                };
+                pending = Some((Some(kind), None, None));
+            } else if let Some(rest) = line.strip_prefix("    start:") {
+                let start: f64 = rest
+                    .trim()
+                    .parse()
+                    .map_err(|_| ScheduleError::Malformed(format!("bad start value: {rest}")))?;
+                match &mut pending {
+                    Some((_, start_slot, _)) => *start_slot = Some(start),
+                    None => return Err(ScheduleError::Malformed("start without a preceding kind".into())),
+                }
+            } else if let Some(rest) = line.strip_prefix("    end:") {
+                let end = match rest.trim() {
+                    "null" => None,
+                    other => Some(
+                        other
+                            .parse::<f64>()
+                            .map_err(|_| ScheduleError::Malformed(format!("bad end value: {other}")))?,
+                    ),
+                };
+                match &mut pending {
+                    Some((_, _, end_slot)) => *end_slot = Some(end),
+                    None => return Err(ScheduleError::Malformed("end without a preceding kind".into())),
+                }
+            } else if let Some(rest) = line.strip_prefix("signal_id:") {
+                signal_id = Some(rest.trim().to_string());
+            } else if let Some(rest) = line.strip_prefix("unit:") {
+                unit = Some(match rest.trim() {
+                    "sample_index" => TimeUnit::SampleIndex,
+                    "seconds" => TimeUnit::Seconds,
+                    other => return Err(ScheduleError::Malformed(format!("unknown unit: {other}"))),
+                });
+            } else if let Some(rest) = line.strip_prefix("default_visibility:") {
+                default_visibility = Some(match rest.trim() {
+                    "visible" => Visibility::Visible,
+                    "hidden" => Visibility::Hidden,
+                    other => return Err(ScheduleError::Malformed(format!("unknown visibility: {other}"))),
+                });
+            } else if line.trim() == "epochs:" {
+                continue;
+            } else {
+                return Err(ScheduleError::Malformed(format!("unrecognized line: {line}")));
+            }
+        }
+        flush(&mut pending, &mut epochs)?;
+
+        let schedule = SamplingSchedule {
+            signal_id: signal_id.ok_or_else(|| ScheduleError::Malformed("missing signal_id".into()))?,
+            unit: unit.ok_or_else(|| ScheduleError::Malformed("missing unit".into()))?,
+            default_visibility: default_visibility
+                .ok_or_else(|| ScheduleError::Malformed("missing default_visibility".into()))?,
+            epochs,
+        };
+        schedule.validate()?;
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> SamplingSchedule {
+        SamplingSchedule {
+            signal_id: "ecg_lead_ii".to_string(),
+            unit: TimeUnit::Seconds,
+            default_visibility: Visibility::Hidden,
+            epochs: vec![
+                Epoch { kind: EpochKind::Inclusion, start: 0.0, end: Some(120.0) },
+                Epoch { kind: EpochKind::Exclusion, start: 45.0, end: Some(50.0) },
+                Epoch { kind: EpochKind::Inclusion, start: 120.0, end: None },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_exclusion_wins_over_overlapping_inclusion() {
+        let schedule = schedule();
+        assert!(schedule.is_admissible(30.0));
+        assert!(!schedule.is_admissible(47.0));
+        assert!(schedule.is_admissible(60.0));
+    }
+
+    #[test]
+    fn test_hidden_default_masks_times_outside_any_epoch() {
+        let schedule = schedule();
+        assert!(!schedule.is_admissible(-1.0));
+    }
+
+    #[test]
+    fn test_open_ended_inclusion_stays_visible_until_signal_end() {
+        let schedule = schedule();
+        assert!(schedule.is_admissible(1_000_000.0));
+    }
+
+    #[test]
+    fn test_admissible_mask_matches_per_point_checks() {
+        let schedule = schedule();
+        let times = vec![10.0, 47.0, 200.0];
+        let mask = schedule.admissible_mask(&times);
+        assert_eq!(mask, vec![true, false, true]);
+    }
+
+    #[test]
+    fn test_validate_rejects_inverted_range() {
+        let mut schedule = schedule();
+        schedule.epochs.push(Epoch { kind: EpochKind::Inclusion, start: 10.0, end: Some(5.0) });
+        assert!(matches!(schedule.validate(), Err(ScheduleError::InvertedRange { .. })));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_range() {
+        let mut schedule = schedule();
+        schedule.epochs.push(Epoch { kind: EpochKind::Exclusion, start: 10.0, end: Some(10.0) });
+        assert!(schedule.validate().is_err());
+    }
+
+    #[test]
+    fn test_yaml_round_trips() {
+        let schedule = schedule();
+        let yaml = schedule.to_yaml();
+        let restored = SamplingSchedule::from_yaml(&yaml).expect("parses");
+        assert_eq!(restored, schedule);
+    }
+
+    #[test]
+    fn test_from_yaml_rejects_malformed_unit() {
+        let yaml = "signal_id: x\nunit: furlongs\ndefault_visibility: hidden\nepochs:\n";
+        assert!(SamplingSchedule::from_yaml(yaml).is_err());
+    }
+}
@@ -0,0 +1,293 @@
+//! Spatial Pattern Database: a k-d tree over the `(sk, st, se)`
+//! S-entropy coordinate space backing nearest-neighbor transfer lookups
+//! for stored [`CrossDomainPattern`]s, plus an append-only JSONL log
+//! format so the database can be persisted and reloaded across runs.
+//!
+//! Unlike [`crate::transfer_matching::PatternTransferDb`] (which compares
+//! whole oscillatory *signatures* via DTW), this index is keyed purely by
+//! the 3-dimensional `SCoord` of a pattern's region — the same
+//! augmented-tree spirit as [`crate::genomic_ops`]'s interval tree, but
+//! split on a rotating coordinate axis instead of an interval endpoint.
+//! The tree is rebuilt from every stored record on each `query_k_nearest`
+//! call, so a query is not sub-linear in the number of stored patterns;
+//! the descent itself skips subtrees that cannot beat the current `k`-th
+//! best match.
+
+use serde::{Deserialize, Serialize};
+use vb_core::types::SCoord;
+
+/// A cross-domain pattern anchored to a point in S-entropy space.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossDomainPattern {
+    pub coord: SCoord,
+    pub domain: String,
+    pub label: String,
+}
+
+/// A stored pattern returned from a nearest-neighbor query, with its
+/// Euclidean distance and a derived transfer-applicability score.
+#[derive(Debug, Clone)]
+pub struct PatternMatch {
+    pub pattern: CrossDomainPattern,
+    pub distance: f64,
+    pub transfer_score: f64,
+}
+
+/// Provenance for a warm-started solve: which stored pattern seeded it.
+#[derive(Debug, Clone)]
+pub struct WarmStartSeed {
+    pub source_label: String,
+    pub transfer_score: f64,
+}
+
+fn axis_value(coord: &SCoord, axis: usize) -> f64 {
+    match axis % 3 {
+        0 => coord.sk,
+        1 => coord.st,
+        _ => coord.se,
+    }
+}
+
+fn squared_distance(a: &SCoord, b: &SCoord) -> f64 {
+    (a.sk - b.sk).powi(2) + (a.st - b.st).powi(2) + (a.se - b.se).powi(2)
+}
+
+struct KdNode {
+    record_index: usize,
+    axis: usize,
+    left: Option<Box<KdNode>>,
+    right: Option<Box<KdNode>>,
+}
+
+fn build_kd_node(indices: &mut [usize], records: &[CrossDomainPattern], depth: usize) -> Option<Box<KdNode>> {
+    if indices.is_empty() {
+        return None;
+    }
+    let axis = depth % 3;
+    indices.sort_by(|&a, &b| {
+        axis_value(&records[a].coord, axis)
+            .partial_cmp(&axis_value(&records[b].coord, axis))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mid = indices.len() / 2;
+    let record_index = indices[mid];
+    let (left_indices, rest) = indices.split_at_mut(mid);
+    let right_indices = &mut rest[1..];
+
+    let left = build_kd_node(left_indices, records, depth + 1);
+    let right = build_kd_node(right_indices, records, depth + 1);
+
+    Some(Box::new(KdNode { record_index, axis, left, right }))
+}
+
+/// Recursively visits `node`, keeping the `k` closest records seen so far
+/// in `best` (sorted ascending by squared distance), pruning a branch
+/// whenever its splitting plane is already farther than the current
+/// k-th best distance.
+fn query_kd_node(
+    node: &KdNode,
+    records: &[CrossDomainPattern],
+    query: &SCoord,
+    k: usize,
+    best: &mut Vec<(f64, usize)>,
+) {
+    let dist = squared_distance(query, &records[node.record_index].coord);
+    best.push((dist, node.record_index));
+    best.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    best.truncate(k);
+
+    let axis_gap = axis_value(query, node.axis) - axis_value(&records[node.record_index].coord, node.axis);
+    let (near, far) = if axis_gap <= 0.0 { (&node.left, &node.right) } else { (&node.right, &node.left) };
+
+    if let Some(near_node) = near {
+        query_kd_node(near_node, records, query, k, best);
+    }
+    let worst_so_far = best.last().map(|&(d, _)| d).unwrap_or(f64::INFINITY);
+    if best.len() < k || axis_gap * axis_gap < worst_so_far {
+        if let Some(far_node) = far {
+            query_kd_node(far_node, records, query, k, best);
+        }
+    }
+}
+
+/// Append-only, k-d-tree-indexed store of [`CrossDomainPattern`]s.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PatternDatabase {
+    records: Vec<CrossDomainPattern>,
+}
+
+impl PatternDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, pattern: CrossDomainPattern) {
+        self.records.push(pattern);
+    }
+
+    pub fn records(&self) -> &[CrossDomainPattern] {
+        &self.records
+    }
+
+    fn build_index(&self) -> Option<Box<KdNode>> {
+        let mut indices: Vec<usize> = (0..self.records.len()).collect();
+        build_kd_node(&mut indices, &self.records, 0)
+    }
+
+    /// The `k` stored patterns nearest `query` in S-entropy space, ranked
+    /// by ascending distance, each with an `exp(-distance)`
+    /// transfer-applicability score.
+    pub fn query_k_nearest(&self, query: &SCoord, k: usize) -> Vec<PatternMatch> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let Some(root) = self.build_index() else {
+            return Vec::new();
+        };
+
+        let mut best = Vec::new();
+        query_kd_node(&root, &self.records, query, k, &mut best);
+
+        best.into_iter()
+            .map(|(squared, index)| {
+                let distance = squared.sqrt();
+                PatternMatch {
+                    pattern: self.records[index].clone(),
+                    distance,
+                    transfer_score: (-distance).exp(),
+                }
+            })
+            .collect()
+    }
+
+    /// Warm-starts from the single best-matching prior pattern when
+    /// `cross_domain_transfer` is enabled, returning `None` otherwise (or
+    /// if the database is empty) — the seed a `solve`-style caller would
+    /// record in its result for provenance.
+    pub fn warm_start(&self, query: &SCoord, cross_domain_transfer: bool) -> Option<WarmStartSeed> {
+        if !cross_domain_transfer {
+            return None;
+        }
+        self.query_k_nearest(query, 1).into_iter().next().map(|m| WarmStartSeed {
+            source_label: m.pattern.label,
+            transfer_score: m.transfer_score,
+        })
+    }
+
+    /// Appends `pattern` to an append-only JSONL log (one record per
+    /// line).
+    pub fn append_to_log(log: &mut String, pattern: &CrossDomainPattern) -> Result<(), serde_json::Error> {
+        log.push_str(&serde_json::to_string(pattern)?);
+        log.push('\n');
+        Ok(())
+    }
+
+    /// Rebuilds a database from a JSONL append-only log previously built
+    /// with [`Self::append_to_log`]. Blank lines are ignored.
+    pub fn from_log(log: &str) -> Result<Self, serde_json::Error> {
+        let mut records = Vec::new();
+        for line in log.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            records.push(serde_json::from_str(line)?);
+        }
+        Ok(Self { records })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(sk: f64, st: f64, se: f64) -> SCoord {
+        SCoord::new(sk, st, se).unwrap()
+    }
+
+    fn pattern(sk: f64, st: f64, se: f64, label: &str) -> CrossDomainPattern {
+        CrossDomainPattern {
+            coord: coord(sk, st, se),
+            domain: "cardiac".to_string(),
+            label: label.to_string(),
+        }
+    }
+
+    fn sample_db() -> PatternDatabase {
+        let mut db = PatternDatabase::new();
+        db.insert(pattern(0.1, 0.1, 0.1, "a"));
+        db.insert(pattern(0.9, 0.9, 0.9, "b"));
+        db.insert(pattern(0.5, 0.5, 0.5, "c"));
+        db.insert(pattern(0.11, 0.09, 0.1, "d"));
+        db
+    }
+
+    fn linear_scan_nearest(db: &PatternDatabase, query: &SCoord, k: usize) -> Vec<String> {
+        let mut scored: Vec<(f64, String)> = db
+            .records()
+            .iter()
+            .map(|r| (squared_distance(query, &r.coord), r.label.clone()))
+            .collect();
+        scored.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        scored.into_iter().take(k).map(|(_, label)| label).collect()
+    }
+
+    #[test]
+    fn test_k_nearest_matches_linear_scan() {
+        let db = sample_db();
+        let query = coord(0.1, 0.1, 0.1);
+        let found: Vec<String> = db.query_k_nearest(&query, 2).into_iter().map(|m| m.pattern.label).collect();
+        let expected = linear_scan_nearest(&db, &query, 2);
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_exact_match_has_zero_distance_and_full_transfer_score() {
+        let db = sample_db();
+        let query = coord(0.1, 0.1, 0.1);
+        let top = &db.query_k_nearest(&query, 1)[0];
+        assert_eq!(top.pattern.label, "a");
+        assert!(top.distance < 1e-9);
+        assert!((top.transfer_score - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_query_k_nearest_empty_database_returns_empty() {
+        let db = PatternDatabase::new();
+        assert!(db.query_k_nearest(&coord(0.5, 0.5, 0.5), 3).is_empty());
+    }
+
+    #[test]
+    fn test_warm_start_disabled_returns_none() {
+        let db = sample_db();
+        assert!(db.warm_start(&coord(0.1, 0.1, 0.1), false).is_none());
+    }
+
+    #[test]
+    fn test_warm_start_enabled_returns_best_match_provenance() {
+        let db = sample_db();
+        let seed = db.warm_start(&coord(0.1, 0.1, 0.1), true).expect("expected a seed");
+        assert_eq!(seed.source_label, "a");
+    }
+
+    #[test]
+    fn test_append_and_reload_log_round_trips() {
+        let db = sample_db();
+        let mut log = String::new();
+        for record in db.records() {
+            PatternDatabase::append_to_log(&mut log, record).expect("serializes");
+        }
+
+        let reloaded = PatternDatabase::from_log(&log).expect("deserializes");
+        assert_eq!(reloaded.records().len(), db.records().len());
+        let found: Vec<String> =
+            reloaded.query_k_nearest(&coord(0.1, 0.1, 0.1), 1).into_iter().map(|m| m.pattern.label).collect();
+        assert_eq!(found, vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn test_from_log_skips_blank_lines() {
+        let db = PatternDatabase::from_log("\n\n").expect("empty log parses");
+        assert!(db.records().is_empty());
+    }
+}
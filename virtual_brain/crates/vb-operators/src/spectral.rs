@@ -0,0 +1,344 @@
+//! Spectral Operators: FFT-based frequency analysis of time series.
+//!
+//! Implements an iterative radix-2 Cooley-Tukey FFT (and its inverse) and
+//! the helpers needed to extract the dominant oscillation frequency from a
+//! sampled signal (e.g. the consciousness time series produced by
+//! `neural_ops`). [`fft`]/[`ifft`] are also reused directly by
+//! `charge_ops::charge_continuity_spectral` for exact periodic-domain
+//! divergence evaluation.
+
+use ndarray::Array1;
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// In-place iterative radix-2 Cooley-Tukey FFT.
+///
+/// `data.len()` must be a power of two. Uses bit-reversal permutation
+/// followed by `log2(N)` butterfly stages with precomputed twiddle
+/// factors `exp(-2*pi*i*k/N)`.
+pub fn fft(data: &mut Vec<Complex64>) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    assert!(n.is_power_of_two(), "FFT length must be a power of two");
+
+    // Bit-reversal permutation.
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = reverse_bits(i, bits);
+        if j > i {
+            data.swap(i, j);
+        }
+    }
+
+    // Butterfly stages.
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let theta = -2.0 * PI / len as f64;
+        let w_len = Complex64::from_polar(1.0, theta);
+
+        let mut start = 0;
+        while start < n {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..half {
+                let u = data[start + k];
+                let v = data[start + k + half] * w;
+                data[start + k] = u + v;
+                data[start + k + half] = u - v;
+                w *= w_len;
+            }
+            start += len;
+        }
+        len *= 2;
+    }
+}
+
+/// In-place inverse FFT, via the standard conjugate trick:
+/// `ifft(x) = conj(fft(conj(x))) / N`. `data.len()` must be a power of two.
+pub fn ifft(data: &mut Vec<Complex64>) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    for x in data.iter_mut() {
+        *x = x.conj();
+    }
+    fft(data);
+    for x in data.iter_mut() {
+        *x = x.conj() / n as f64;
+    }
+}
+
+fn reverse_bits(mut x: usize, bits: u32) -> usize {
+    let mut result = 0;
+    for _ in 0..bits {
+        result = (result << 1) | (x & 1);
+        x >>= 1;
+    }
+    result
+}
+
+/// Next power of two greater than or equal to `n`.
+pub fn next_power_of_two(n: usize) -> usize {
+    n.next_power_of_two().max(1)
+}
+
+/// Hann window: `0.5 * (1 - cos(2*pi*n/(N-1)))`.
+pub fn hann_window(n: usize) -> Vec<f64> {
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+    (0..n)
+        .map(|i| 0.5 * (1.0 - (2.0 * PI * i as f64 / (n - 1) as f64).cos()))
+        .collect()
+}
+
+/// One-sided power spectrum of a real-valued time series, plus its
+/// frequency bins.
+#[derive(Debug, Clone)]
+pub struct PowerSpectrum {
+    /// `|X[k]|^2` for `k` in `[0, N/2]`.
+    pub power: Array1<f64>,
+    /// Frequency bins `k / (N * dt)` matching `power`.
+    pub frequencies: Array1<f64>,
+}
+
+impl PowerSpectrum {
+    /// Dominant non-DC frequency: the bin with maximum power, ignoring
+    /// the DC (k=0) bin.
+    pub fn dominant_frequency(&self) -> f64 {
+        if self.power.len() <= 1 {
+            return 0.0;
+        }
+        let (idx, _) = self
+            .power
+            .iter()
+            .enumerate()
+            .skip(1)
+            .fold((1usize, self.power[1]), |(best_idx, best_val), (i, &v)| {
+                if v > best_val {
+                    (i, v)
+                } else {
+                    (best_idx, best_val)
+                }
+            });
+        self.frequencies[idx]
+    }
+}
+
+/// Compute the one-sided power spectrum of a real-valued series sampled
+/// at fixed `dt`. Zero-pads to the next power of two and optionally
+/// applies a Hann window before transforming.
+pub fn power_spectrum(series: &Array1<f64>, dt: f64, apply_window: bool) -> PowerSpectrum {
+    let n_samples = series.len();
+    let n_fft = next_power_of_two(n_samples.max(1));
+
+    let window = if apply_window {
+        Some(hann_window(n_samples))
+    } else {
+        None
+    };
+
+    let mut buffer: Vec<Complex64> = (0..n_fft)
+        .map(|i| {
+            if i < n_samples {
+                let w = window.as_ref().map(|w| w[i]).unwrap_or(1.0);
+                Complex64::new(series[i] * w, 0.0)
+            } else {
+                Complex64::new(0.0, 0.0)
+            }
+        })
+        .collect();
+
+    fft(&mut buffer);
+
+    let n_bins = n_fft / 2 + 1;
+    let power = Array1::from_iter((0..n_bins).map(|k| buffer[k].norm_sqr()));
+    let frequencies =
+        Array1::from_iter((0..n_bins).map(|k| k as f64 / (n_fft as f64 * dt)));
+
+    PowerSpectrum { power, frequencies }
+}
+
+/// Convenience: dominant frequency of a real-valued series sampled at
+/// fixed `dt`.
+pub fn dominant_frequency(series: &Array1<f64>, dt: f64, apply_window: bool) -> f64 {
+    power_spectrum(series, dt, apply_window).dominant_frequency()
+}
+
+/// Summary of a time series' frequency content: its top peaks and the
+/// spectral entropy of its power distribution, used to distinguish
+/// phase-locked (a few dominant peaks, low entropy) from chaotic
+/// (power spread across many frequencies, high entropy) regimes more
+/// rigorously than a bare variance threshold.
+#[derive(Debug, Clone)]
+pub struct Spectrum {
+    /// Frequencies of the top peaks (Hz), sorted by descending power.
+    pub dominant_frequencies: Vec<f64>,
+    /// Power at each of those peaks, same order as `dominant_frequencies`.
+    pub dominant_magnitudes: Vec<f64>,
+    /// Shannon entropy (nats) of the normalized non-DC power spectrum.
+    /// Low for a few sharp peaks (phase-locked), high for power spread
+    /// broadly across frequencies (chaotic).
+    pub spectral_entropy: f64,
+    /// `spectral_entropy` normalized to `[0, 1]` by `ln(#non-DC bins)`,
+    /// so it is comparable across runs with different sample counts:
+    /// near 0 for a single dominant oscillation, near 1 for broadband
+    /// (incoherent) power.
+    pub normalized_entropy: f64,
+}
+
+/// Compute a [`Spectrum`] for a sampled time series: detrends (subtracts
+/// the mean) before windowing so a nonzero DC offset doesn't bias the
+/// Hann-windowed FFT, then reports the `n_peaks` dominant non-DC
+/// frequencies/magnitudes plus the (normalized) spectral entropy of the
+/// whole non-DC power distribution.
+pub fn spectrum(series: &Array1<f64>, dt: f64, n_peaks: usize) -> Spectrum {
+    let mean = series.iter().sum::<f64>() / series.len().max(1) as f64;
+    let detrended = series.mapv(|v| v - mean);
+    let ps = power_spectrum(&detrended, dt, true);
+
+    if ps.power.len() <= 1 {
+        return Spectrum {
+            dominant_frequencies: Vec::new(),
+            dominant_magnitudes: Vec::new(),
+            spectral_entropy: 0.0,
+            normalized_entropy: 0.0,
+        };
+    }
+
+    let total: f64 = ps.power.iter().skip(1).sum();
+    let spectral_entropy = if total > 0.0 {
+        -ps.power
+            .iter()
+            .skip(1)
+            .filter(|&&p| p > 0.0)
+            .map(|&p| {
+                let pn = p / total;
+                pn * pn.ln()
+            })
+            .sum::<f64>()
+    } else {
+        0.0
+    };
+
+    let n_bins = ps.power.len() - 1;
+    let normalized_entropy = if n_bins > 1 {
+        spectral_entropy / (n_bins as f64).ln()
+    } else {
+        0.0
+    };
+
+    let mut ranked: Vec<(f64, f64)> = ps
+        .power
+        .iter()
+        .zip(ps.frequencies.iter())
+        .skip(1)
+        .map(|(&p, &f)| (f, p))
+        .collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    ranked.truncate(n_peaks);
+
+    let (dominant_frequencies, dominant_magnitudes) = ranked.into_iter().unzip();
+
+    Spectrum {
+        dominant_frequencies,
+        dominant_magnitudes,
+        spectral_entropy,
+        normalized_entropy,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_power_of_two() {
+        assert_eq!(next_power_of_two(1), 1);
+        assert_eq!(next_power_of_two(5), 8);
+        assert_eq!(next_power_of_two(16), 16);
+    }
+
+    #[test]
+    fn test_fft_of_dc_signal() {
+        let mut data = vec![Complex64::new(1.0, 0.0); 8];
+        fft(&mut data);
+        assert!((data[0].re - 8.0).abs() < 1e-9);
+        for v in data.iter().skip(1) {
+            assert!(v.norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_fft_ifft_round_trips() {
+        let original: Vec<Complex64> =
+            (0..8).map(|i| Complex64::new(i as f64, (i as f64) * 0.5)).collect();
+        let mut data = original.clone();
+        fft(&mut data);
+        ifft(&mut data);
+        for (a, b) in data.iter().zip(original.iter()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_dominant_frequency_of_sine() {
+        let dt = 1.0 / 256.0;
+        let freq = 10.0;
+        let n = 256;
+        let series = Array1::from_iter(
+            (0..n).map(|i| (2.0 * PI * freq * i as f64 * dt).sin()),
+        );
+
+        let measured = dominant_frequency(&series, dt, true);
+        assert!((measured - freq).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_spectrum_of_sine_has_low_entropy_single_peak() {
+        let dt = 1.0 / 256.0;
+        let freq = 10.0;
+        let n = 256;
+        let series = Array1::from_iter(
+            (0..n).map(|i| (2.0 * PI * freq * i as f64 * dt).sin()),
+        );
+
+        let spec = spectrum(&series, dt, 3);
+        assert_eq!(spec.dominant_frequencies.len(), 3);
+        assert!((spec.dominant_frequencies[0] - freq).abs() < 1.0);
+        assert!(spec.dominant_magnitudes[0] >= spec.dominant_magnitudes[1]);
+        assert!(spec.spectral_entropy >= 0.0);
+    }
+
+    #[test]
+    fn test_spectrum_of_white_noise_has_higher_entropy_than_sine() {
+        let dt = 1.0 / 256.0;
+        let n = 256;
+        let sine = Array1::from_iter(
+            (0..n).map(|i| (2.0 * PI * 10.0 * i as f64 * dt).sin()),
+        );
+        // Deterministic pseudo-noise: sum of many incommensurate
+        // frequencies spreads power across many bins.
+        let noisy = Array1::from_iter((0..n).map(|i| {
+            let t = i as f64 * dt;
+            (2.0 * PI * 10.0 * t).sin()
+                + (2.0 * PI * 37.0 * t).sin()
+                + (2.0 * PI * 63.0 * t).sin()
+                + (2.0 * PI * 91.0 * t).sin()
+        }));
+
+        let sine_entropy = spectrum(&sine, dt, 3).spectral_entropy;
+        let noisy_entropy = spectrum(&noisy, dt, 3).spectral_entropy;
+        assert!(noisy_entropy > sine_entropy);
+    }
+
+    #[test]
+    fn test_hann_window_endpoints() {
+        let w = hann_window(10);
+        assert!(w[0].abs() < 1e-10);
+        assert!(w[9].abs() < 1e-10);
+    }
+}
@@ -0,0 +1,487 @@
+//! Transfer Matching: banded dynamic-time-warping similarity between
+//! [`OscillatorySignature`]s, used to score cross-domain pattern transfer.
+//!
+//! [`PatternTransferDb`] also retains a history of solved [`TransferCase`]s
+//! (signature, chosen algorithm, realized [`PerformanceMetrics`]) so a
+//! caller can query for a warm start on a new oscillation via
+//! [`PatternTransferDb::query_nearest`] before falling back to a
+//! hard-coded algorithm choice, then self-curate the store with
+//! [`PatternTransferDb::record_transfer_outcome`] based on whether the
+//! transfer actually paid off.
+
+use serde::{Deserialize, Serialize};
+
+/// Frequency/phase/amplitude fingerprint of an oscillatory pattern, used
+/// to compare patterns across domains (e.g. cardiac vs. neural).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OscillatorySignature {
+    pub frequency_components: Vec<f64>,
+    pub phase_relationships: Vec<f64>,
+    pub amplitude_modulation: Vec<f64>,
+}
+
+/// A pattern learned in one domain, scored for how well it transfers to
+/// others.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossDomainOscillatoryPattern {
+    pub source_oscillation_type: String,
+    pub target_domains: Vec<String>,
+    pub pattern_signature: OscillatorySignature,
+    pub transfer_efficiency: f64,
+}
+
+/// Per-sample feature used by the DTW matcher: one frequency, phase, and
+/// amplitude component stacked together.
+fn feature_rows(signature: &OscillatorySignature) -> Vec<[f64; 3]> {
+    let len = signature
+        .frequency_components
+        .len()
+        .max(signature.phase_relationships.len())
+        .max(signature.amplitude_modulation.len());
+
+    (0..len)
+        .map(|i| {
+            [
+                *signature.frequency_components.get(i).unwrap_or(&0.0),
+                *signature.phase_relationships.get(i).unwrap_or(&0.0),
+                *signature.amplitude_modulation.get(i).unwrap_or(&0.0),
+            ]
+        })
+        .collect()
+}
+
+/// Rescale each of the three feature channels (columns) to unit variance
+/// across the row sequence, so no single channel dominates `local_dist`.
+fn normalize_channels(rows: &mut [[f64; 3]]) {
+    if rows.is_empty() {
+        return;
+    }
+    for channel in 0..3 {
+        let n = rows.len() as f64;
+        let mean = rows.iter().map(|r| r[channel]).sum::<f64>() / n;
+        let variance = rows.iter().map(|r| (r[channel] - mean).powi(2)).sum::<f64>() / n;
+        let std_dev = variance.sqrt();
+        if std_dev > 0.0 {
+            for row in rows.iter_mut() {
+                row[channel] = (row[channel] - mean) / std_dev;
+            }
+        }
+    }
+}
+
+fn local_dist(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt()
+}
+
+/// Sakoe-Chiba-banded DTW distance between two signatures: average warp
+/// cost along the optimal alignment path (total cost divided by the
+/// number of steps on that path). Runs in `O(n * w)` instead of `O(n^2)` by rejecting any
+/// `(i, j)` cell with `|i - j| > w`.
+///
+/// `band_width` must be at least `|len_a - len_b|`, otherwise the
+/// diagonal from `(0, 0)` to `(len_a, len_b)` would fall outside the band
+/// and no path would exist; it is widened automatically if needed.
+pub fn dtw_distance(a: &OscillatorySignature, b: &OscillatorySignature, band_width: usize) -> f64 {
+    let mut rows_a = feature_rows(a);
+    let mut rows_b = feature_rows(b);
+    normalize_channels(&mut rows_a);
+    normalize_channels(&mut rows_b);
+
+    let n = rows_a.len();
+    let m = rows_b.len();
+    if n == 0 || m == 0 {
+        return f64::INFINITY;
+    }
+
+    let w = band_width.max(n.abs_diff(m));
+    let mut cost = vec![vec![f64::INFINITY; m + 1]; n + 1];
+    let mut steps = vec![vec![0usize; m + 1]; n + 1];
+    cost[0][0] = 0.0;
+
+    for i in 1..=n {
+        let j_lo = 1.max(i.saturating_sub(w));
+        let j_hi = m.min(i + w);
+        for j in j_lo..=j_hi {
+            let d = local_dist(&rows_a[i - 1], &rows_b[j - 1]);
+            let (best_prev, prev_steps) = [(i - 1, j), (i, j - 1), (i - 1, j - 1)]
+                .iter()
+                .map(|&(pi, pj)| (cost[pi][pj], steps[pi][pj]))
+                .fold((f64::INFINITY, 0), |best, c| if c.0 < best.0 { c } else { best });
+            cost[i][j] = d + best_prev;
+            steps[i][j] = prev_steps + 1;
+        }
+    }
+
+    let warp_cost = cost[n][m];
+    if !warp_cost.is_finite() {
+        return f64::INFINITY;
+    }
+
+    // Number of cells on the optimal path (at least max(n, m)).
+    warp_cost / steps[n][m] as f64
+}
+
+/// Maps a DTW distance to a `(0, 1]` transfer-efficiency score: identical
+/// signatures (`distance = 0`) score `1.0`, and efficiency decays
+/// exponentially as the signatures diverge.
+pub fn transfer_efficiency(a: &OscillatorySignature, b: &OscillatorySignature, band_width: usize) -> f64 {
+    if a.frequency_components.is_empty()
+        && a.phase_relationships.is_empty()
+        && a.amplitude_modulation.is_empty()
+    {
+        return 0.0;
+    }
+    if b.frequency_components.is_empty()
+        && b.phase_relationships.is_empty()
+        && b.amplitude_modulation.is_empty()
+    {
+        return 0.0;
+    }
+
+    let distance = dtw_distance(a, b, band_width);
+    if !distance.is_finite() {
+        return 0.0;
+    }
+    (-distance).exp()
+}
+
+/// Performance realized by a chosen algorithm on one solved case, used to
+/// judge whether reusing that case via transfer actually pays off.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PerformanceMetrics {
+    pub speedup_factor: f64,
+    pub accuracy_improvement: f64,
+    pub memory_reduction: f64,
+}
+
+/// Warm-start parameters seeded from a prior solved case: an initial
+/// window size and per-unit alert threshold to try before falling back to
+/// defaults. Stands in for the spec's `FuzzyWindowParams`, which has no
+/// analog in this crate.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WarmStartParams {
+    pub window_size: usize,
+    pub threshold: f64,
+}
+
+/// A solved case retained for transfer learning: the signature that was
+/// matched, the algorithm chosen for it, the performance it realized, and
+/// a self-curation `weight` in `(0, 1]` that is down-adjusted every time
+/// reusing this case underperforms a fresh solve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferCase {
+    pub pattern_signature: OscillatorySignature,
+    pub algorithm: String,
+    pub metrics: PerformanceMetrics,
+    pub warm_start: WarmStartParams,
+    pub weight: f64,
+}
+
+/// The nearest stored [`TransferCase`] to a query signature, with the raw
+/// cosine similarity that qualified it.
+#[derive(Debug, Clone)]
+pub struct TransferMatch {
+    pub case_index: usize,
+    pub case: TransferCase,
+    pub similarity: f64,
+}
+
+const WEIGHT_DECAY: f64 = 0.7;
+const WEIGHT_RECOVERY: f64 = 1.1;
+const MIN_WEIGHT: f64 = 0.05;
+
+fn feature_vector(signature: &OscillatorySignature) -> Vec<f64> {
+    signature
+        .frequency_components
+        .iter()
+        .chain(signature.phase_relationships.iter())
+        .chain(signature.amplitude_modulation.iter())
+        .copied()
+        .collect()
+}
+
+/// Cosine similarity between two feature vectors; `0.0` if either is
+/// empty or zero-norm (no shared scale to compare against).
+fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len().min(b.len());
+    if n == 0 {
+        return 0.0;
+    }
+    let dot: f64 = a[..n].iter().zip(&b[..n]).map(|(x, y)| x * y).sum();
+    let norm_a = a[..n].iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b[..n].iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm_a <= 0.0 || norm_b <= 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// In-memory store of [`CrossDomainOscillatoryPattern`]s, gated on a
+/// minimum `transfer_efficiency` so only patterns that transfer well
+/// across domains are retained, plus a [`TransferCase`] history that
+/// `auto_select_and_apply`-style callers can query for a warm start
+/// before falling back to a hard-coded algorithm choice.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PatternTransferDb {
+    patterns: Vec<CrossDomainOscillatoryPattern>,
+    min_transfer_efficiency: f64,
+    transfer_cases: Vec<TransferCase>,
+}
+
+impl PatternTransferDb {
+    pub fn new(min_transfer_efficiency: f64) -> Self {
+        Self {
+            patterns: Vec::new(),
+            min_transfer_efficiency,
+            transfer_cases: Vec::new(),
+        }
+    }
+
+    /// Score `pattern` against every existing entry; insert it only if
+    /// its `transfer_efficiency` field already meets the gate AND it is
+    /// not a near-duplicate (DTW efficiency above the gate) of an
+    /// existing entry for the same source type.
+    pub fn offer(&mut self, pattern: CrossDomainOscillatoryPattern, band_width: usize) -> bool {
+        if pattern.transfer_efficiency < self.min_transfer_efficiency {
+            return false;
+        }
+        let is_duplicate = self.patterns.iter().any(|existing| {
+            existing.source_oscillation_type == pattern.source_oscillation_type
+                && transfer_efficiency(&existing.pattern_signature, &pattern.pattern_signature, band_width)
+                    >= self.min_transfer_efficiency
+        });
+        if is_duplicate {
+            return false;
+        }
+        self.patterns.push(pattern);
+        true
+    }
+
+    pub fn patterns(&self) -> &[CrossDomainOscillatoryPattern] {
+        &self.patterns
+    }
+
+    /// Records a newly solved case (signature, chosen algorithm, realized
+    /// performance, warm-start seed) so future similar oscillations can
+    /// reuse it. The case is stored with the `weight` it carries;
+    /// [`Self::record_transfer_outcome`] adjusts it as the case gets reused.
+    pub fn record_case(&mut self, case: TransferCase) {
+        self.transfer_cases.push(case);
+    }
+
+    pub fn transfer_cases(&self) -> &[TransferCase] {
+        &self.transfer_cases
+    }
+
+    /// Finds the stored case most similar to `query` by cosine similarity
+    /// over the frequency/phase/amplitude feature vector, among cases
+    /// whose similarity meets `strategic_sufficiency_threshold`. Ties
+    /// among qualifying cases are broken toward higher `weight`, so
+    /// down-curated cases lose out to fresher ones with equal similarity.
+    /// Returns `None` if no stored case clears the threshold.
+    pub fn query_nearest(
+        &self,
+        query: &OscillatorySignature,
+        strategic_sufficiency_threshold: f64,
+    ) -> Option<TransferMatch> {
+        let query_features = feature_vector(query);
+        self.transfer_cases
+            .iter()
+            .enumerate()
+            .map(|(index, case)| {
+                let similarity = cosine_similarity(&query_features, &feature_vector(&case.pattern_signature));
+                (index, similarity)
+            })
+            .filter(|&(_, similarity)| similarity >= strategic_sufficiency_threshold)
+            .max_by(|a, b| {
+                let key_a = (a.1, self.transfer_cases[a.0].weight);
+                let key_b = (b.1, self.transfer_cases[b.0].weight);
+                key_a.partial_cmp(&key_b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(index, similarity)| TransferMatch {
+                case_index: index,
+                case: self.transfer_cases[index].clone(),
+                similarity,
+            })
+    }
+
+    /// Self-curates the store: after reusing the case at `case_index` as a
+    /// warm start, call this with the speedup factor actually realized.
+    /// If transfer underperformed the case's stored `speedup_factor`, the
+    /// case's weight decays (making it less likely to win future matches);
+    /// otherwise it recovers back toward `1.0`.
+    pub fn record_transfer_outcome(&mut self, case_index: usize, realized_speedup_factor: f64) {
+        if let Some(case) = self.transfer_cases.get_mut(case_index) {
+            if realized_speedup_factor + 1e-9 < case.metrics.speedup_factor {
+                case.weight = (case.weight * WEIGHT_DECAY).max(MIN_WEIGHT);
+            } else {
+                case.weight = (case.weight * WEIGHT_RECOVERY).min(1.0);
+            }
+        }
+    }
+
+    /// Serializes the whole database (patterns and transfer cases) so
+    /// learned patterns can persist across runs.
+    pub fn export_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Restores a database previously produced by [`Self::export_json`].
+    pub fn import_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signature(freq: Vec<f64>, phase: Vec<f64>, amp: Vec<f64>) -> OscillatorySignature {
+        OscillatorySignature {
+            frequency_components: freq,
+            phase_relationships: phase,
+            amplitude_modulation: amp,
+        }
+    }
+
+    #[test]
+    fn test_identical_signatures_have_zero_distance_and_full_efficiency() {
+        let sig = signature(vec![1.0, 2.0, 3.0], vec![0.1, 0.2, 0.3], vec![0.5, 0.5, 0.5]);
+        let distance = dtw_distance(&sig, &sig, 2);
+        assert!(distance.abs() < 1e-9, "distance = {distance}");
+        assert!((transfer_efficiency(&sig, &sig, 2) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_divergent_signatures_have_lower_efficiency_than_identical() {
+        let a = signature(vec![1.0, 2.0, 3.0, 4.0], vec![0.0; 4], vec![1.0; 4]);
+        let b = signature(vec![10.0, 20.0, 30.0, 40.0], vec![3.0; 4], vec![-1.0; 4]);
+        let identical_efficiency = transfer_efficiency(&a, &a, 2);
+        let divergent_efficiency = transfer_efficiency(&a, &b, 2);
+        assert!(divergent_efficiency < identical_efficiency);
+    }
+
+    #[test]
+    fn test_handles_unequal_length_signatures_within_band() {
+        let a = signature(vec![1.0, 2.0, 3.0, 4.0, 5.0], vec![0.0; 5], vec![1.0; 5]);
+        let b = signature(vec![1.0, 2.0, 3.0], vec![0.0; 3], vec![1.0; 3]);
+        let distance = dtw_distance(&a, &b, 2);
+        assert!(distance.is_finite());
+    }
+
+    #[test]
+    fn test_empty_signature_yields_zero_efficiency() {
+        let empty = signature(vec![], vec![], vec![]);
+        let populated = signature(vec![1.0, 2.0], vec![0.0, 0.0], vec![1.0, 1.0]);
+        assert_eq!(transfer_efficiency(&empty, &populated, 2), 0.0);
+    }
+
+    #[test]
+    fn test_pattern_transfer_db_rejects_below_gate() {
+        let mut db = PatternTransferDb::new(0.5);
+        let pattern = CrossDomainOscillatoryPattern {
+            source_oscillation_type: "cardiac".to_string(),
+            target_domains: vec!["neural".to_string()],
+            pattern_signature: signature(vec![1.0], vec![0.0], vec![1.0]),
+            transfer_efficiency: 0.1,
+        };
+        assert!(!db.offer(pattern, 2));
+        assert!(db.patterns().is_empty());
+    }
+
+    #[test]
+    fn test_pattern_transfer_db_accepts_above_gate_and_rejects_duplicates() {
+        let mut db = PatternTransferDb::new(0.5);
+        let sig = signature(vec![1.0, 2.0, 3.0], vec![0.0; 3], vec![1.0; 3]);
+        let pattern = CrossDomainOscillatoryPattern {
+            source_oscillation_type: "cardiac".to_string(),
+            target_domains: vec!["neural".to_string()],
+            pattern_signature: sig.clone(),
+            transfer_efficiency: 0.9,
+        };
+        assert!(db.offer(pattern.clone(), 2));
+        assert_eq!(db.patterns().len(), 1);
+
+        assert!(!db.offer(pattern, 2));
+        assert_eq!(db.patterns().len(), 1);
+    }
+
+    fn transfer_case(freq: Vec<f64>, algorithm: &str, speedup_factor: f64) -> TransferCase {
+        TransferCase {
+            pattern_signature: signature(freq, vec![0.0, 0.0, 0.0], vec![1.0, 1.0, 1.0]),
+            algorithm: algorithm.to_string(),
+            metrics: PerformanceMetrics {
+                speedup_factor,
+                accuracy_improvement: 0.1,
+                memory_reduction: 0.2,
+            },
+            warm_start: WarmStartParams {
+                window_size: 32,
+                threshold: 0.5,
+            },
+            weight: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_query_nearest_finds_closest_case_above_threshold() {
+        let mut db = PatternTransferDb::new(0.5);
+        db.record_case(transfer_case(vec![1.0, 2.0, 3.0], "poincare", 2.0));
+        db.record_case(transfer_case(vec![100.0, 5.0, -8.0], "kuramoto", 1.5));
+
+        let query = signature(vec![1.0, 2.0, 3.0], vec![0.0, 0.0, 0.0], vec![1.0, 1.0, 1.0]);
+        let found = db.query_nearest(&query, 0.9).expect("expected a match");
+        assert_eq!(found.case.algorithm, "poincare");
+        assert!(found.similarity > 0.99);
+    }
+
+    #[test]
+    fn test_query_nearest_ranks_by_similarity_before_weight() {
+        let mut db = PatternTransferDb::new(0.5);
+        // A near-perfect signature match, but down-curated to a low weight.
+        let mut decayed = transfer_case(vec![1.0, 2.0, 3.0], "poincare", 2.0);
+        decayed.weight = 0.05;
+        db.record_case(decayed);
+        // A worse (but still above-threshold) match at full weight.
+        db.record_case(transfer_case(vec![1.0, 2.0, 2.2], "kuramoto", 1.5));
+
+        let query = signature(vec![1.0, 2.0, 3.0], vec![0.0, 0.0, 0.0], vec![1.0, 1.0, 1.0]);
+        let found = db.query_nearest(&query, 0.5).expect("expected a match");
+
+        // similarity * weight would rank "kuramoto" (1.0 * 0.9something)
+        // ahead of "poincare" (~1.0 * 0.05); similarity should win instead.
+        assert_eq!(found.case.algorithm, "poincare");
+    }
+
+    #[test]
+    fn test_query_nearest_none_when_no_case_clears_threshold() {
+        let mut db = PatternTransferDb::new(0.5);
+        db.record_case(transfer_case(vec![1.0, 0.0, -1.0], "poincare", 2.0));
+
+        let query = signature(vec![-1.0, 0.0, 1.0], vec![0.0, 0.0, 0.0], vec![1.0, 1.0, 1.0]);
+        assert!(db.query_nearest(&query, 0.99).is_none());
+    }
+
+    #[test]
+    fn test_record_transfer_outcome_decays_weight_on_underperformance() {
+        let mut db = PatternTransferDb::new(0.5);
+        db.record_case(transfer_case(vec![1.0, 2.0, 3.0], "poincare", 2.0));
+
+        db.record_transfer_outcome(0, 1.0);
+        assert!(db.transfer_cases()[0].weight < 1.0);
+
+        db.record_transfer_outcome(0, 3.0);
+        assert!(db.transfer_cases()[0].weight > WEIGHT_DECAY);
+    }
+
+    #[test]
+    fn test_export_import_json_round_trips_transfer_cases() {
+        let mut db = PatternTransferDb::new(0.5);
+        db.record_case(transfer_case(vec![1.0, 2.0, 3.0], "poincare", 2.0));
+
+        let json = db.export_json().expect("serializes");
+        let restored = PatternTransferDb::import_json(&json).expect("deserializes");
+        assert_eq!(restored.transfer_cases().len(), 1);
+        assert_eq!(restored.transfer_cases()[0].algorithm, "poincare");
+    }
+}
@@ -0,0 +1,242 @@
+//! WASM bindings for the Poincare/charge operator pipeline.
+//!
+//! Gated behind the `wasm` feature so the default native build carries no
+//! `wasm-bindgen` dependency. Each entry point mirrors the pattern used
+//! throughout this crate for serializable state: JS passes in a
+//! serde-serialized `SCoord`/`MentalState`/`Vec<f64>` plus plain-value
+//! parameters, and gets back a serde-serialized result (`CompletionResult`
+//! already derives `Serialize`/`Deserialize` for exactly this reason).
+//!
+//! Constraint closures can't cross the WASM boundary, so `complete_wasm`
+//! takes a constraint *name* plus a small JSON params blob instead of a
+//! function value; [`build_constraint`] resolves that into one of a fixed
+//! set of built-in constraints over `SCoord`.
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use ndarray::Array1;
+use vb_core::types::{MentalState, SCoord};
+
+use crate::charge_ops::{charge_equilibrium, minimize_variance};
+use crate::neural_ops::evolve_mental_state;
+use crate::poincare_ops::{complete, equilibrium, target, CompletionResult};
+
+fn to_js_err<E: std::fmt::Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+fn from_value<T: for<'de> Deserialize<'de>>(value: JsValue) -> Result<T, JsValue> {
+    serde_wasm_bindgen::from_value(value).map_err(to_js_err)
+}
+
+fn to_value<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    serde_wasm_bindgen::to_value(value).map_err(to_js_err)
+}
+
+/// Parameters for the named constraints `complete_wasm` can build. Only the
+/// fields a given constraint needs have to be set; the rest are ignored.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConstraintParams {
+    /// Reference point for the `"recurrence"` constraint.
+    pub reference: Option<SCoord>,
+    /// Recurrence radius for the `"recurrence"` constraint.
+    pub epsilon: Option<f64>,
+    /// Target value for the `"consciousness_target"` constraint.
+    pub target_value: Option<f64>,
+}
+
+/// Resolves a constraint name + params blob into one of the built-in
+/// `complete`-compatible constraints, since a JS-supplied closure can't
+/// cross the WASM boundary:
+///
+/// - `"recurrence"`: distance from `params.reference` minus `params.epsilon`
+///   (the same formula as `crate::poincare_ops::recurrence_constraint`, but
+///   over an owned reference point so the closure can outlive this call).
+/// - `"consciousness_target"`: drives the mean of `(sk, st, se)` — the
+///   closest SCoord-space proxy for an overall consciousness level — toward
+///   `params.target_value`.
+/// - `"variance_minimization"`: drives `(sk, st, se)` toward equality with
+///   each other, the SCoord-space analog of `charge_ops::minimize_variance`.
+pub fn build_constraint(name: &str, params: &ConstraintParams) -> Result<Box<dyn Fn(&SCoord) -> f64>, String> {
+    match name {
+        "recurrence" => {
+            let reference = params.reference.ok_or("recurrence constraint requires `reference`")?;
+            let epsilon = params.epsilon.ok_or("recurrence constraint requires `epsilon`")?;
+            // `recurrence_constraint` borrows its reference point with a
+            // lifetime tied to the caller, which can't outlive this
+            // function; reimplement its formula over an owned `SCoord`
+            // (`Copy`) moved into the boxed closure instead.
+            Ok(Box::new(move |s: &SCoord| s.distance(&reference) - epsilon))
+        }
+        "consciousness_target" => {
+            let target_value = params
+                .target_value
+                .ok_or("consciousness_target constraint requires `target_value`")?;
+            Ok(Box::new(move |s: &SCoord| (s.sk + s.st + s.se) / 3.0 - target_value))
+        }
+        "variance_minimization" => Ok(Box::new(|s: &SCoord| {
+            let mean = (s.sk + s.st + s.se) / 3.0;
+            (s.sk - mean).powi(2) + (s.st - mean).powi(2) + (s.se - mean).powi(2)
+        })),
+        other => Err(format!("unknown constraint: {other}")),
+    }
+}
+
+#[cfg(test)]
+mod build_constraint_tests {
+    use super::*;
+
+    #[test]
+    fn test_recurrence_constraint_matches_distance_minus_epsilon() {
+        let reference = SCoord::new(0.2, 0.3, 0.4).unwrap();
+        let params = ConstraintParams {
+            reference: Some(reference),
+            epsilon: Some(0.1),
+            ..ConstraintParams::default()
+        };
+        let constraint = build_constraint("recurrence", &params).unwrap();
+
+        let point = SCoord::new(0.5, 0.3, 0.4).unwrap();
+        let expected = point.distance(&reference) - 0.1;
+        assert!((constraint(&point) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_recurrence_constraint_requires_reference() {
+        let params = ConstraintParams {
+            epsilon: Some(0.1),
+            ..ConstraintParams::default()
+        };
+        let err = build_constraint("recurrence", &params).unwrap_err();
+        assert!(err.contains("reference"));
+    }
+
+    #[test]
+    fn test_recurrence_constraint_requires_epsilon() {
+        let params = ConstraintParams {
+            reference: Some(SCoord::origin()),
+            ..ConstraintParams::default()
+        };
+        let err = build_constraint("recurrence", &params).unwrap_err();
+        assert!(err.contains("epsilon"));
+    }
+
+    #[test]
+    fn test_consciousness_target_constraint_matches_mean_minus_target() {
+        let params = ConstraintParams {
+            target_value: Some(0.4),
+            ..ConstraintParams::default()
+        };
+        let constraint = build_constraint("consciousness_target", &params).unwrap();
+
+        let point = SCoord::new(0.3, 0.6, 0.9).unwrap();
+        let expected = (point.sk + point.st + point.se) / 3.0 - 0.4;
+        assert!((constraint(&point) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_consciousness_target_constraint_requires_target_value() {
+        let err = build_constraint("consciousness_target", &ConstraintParams::default()).unwrap_err();
+        assert!(err.contains("target_value"));
+    }
+
+    #[test]
+    fn test_variance_minimization_constraint_is_zero_at_equal_coords() {
+        let constraint = build_constraint("variance_minimization", &ConstraintParams::default()).unwrap();
+        let point = SCoord::new(0.5, 0.5, 0.5).unwrap();
+        assert!((constraint(&point)).abs() < 1e-12);
+
+        let spread = SCoord::new(0.1, 0.5, 0.9).unwrap();
+        assert!(constraint(&spread) > 0.0);
+    }
+
+    #[test]
+    fn test_unknown_constraint_name_errors() {
+        let err = build_constraint("not_a_real_constraint", &ConstraintParams::default()).unwrap_err();
+        assert!(err.contains("not_a_real_constraint"));
+    }
+}
+
+/// WASM entry point for the COMPLETE operator. `constraint_params` may be
+/// `undefined`/`null` for constraints (like `"variance_minimization"`) that
+/// need no parameters.
+#[wasm_bindgen]
+pub fn complete_wasm(
+    initial: JsValue,
+    constraint_name: &str,
+    constraint_params: JsValue,
+    max_iterations: usize,
+    tolerance: f64,
+    learning_rate: f64,
+    use_diis: bool,
+) -> Result<JsValue, JsValue> {
+    let initial: SCoord = from_value(initial)?;
+    let params: ConstraintParams = if constraint_params.is_undefined() || constraint_params.is_null() {
+        ConstraintParams::default()
+    } else {
+        from_value(constraint_params)?
+    };
+    let constraint = build_constraint(constraint_name, &params).map_err(|e| JsValue::from_str(&e))?;
+
+    let result: CompletionResult = complete(&initial, &[constraint], max_iterations, tolerance, learning_rate, use_diis);
+    to_value(&result)
+}
+
+/// WASM entry point for the TARGET operator.
+#[wasm_bindgen]
+pub fn target_wasm(current: JsValue, target_coord: JsValue, step_size: f64) -> Result<JsValue, JsValue> {
+    let current: SCoord = from_value(current)?;
+    let target_coord: SCoord = from_value(target_coord)?;
+    to_value(&target(&current, &target_coord, step_size))
+}
+
+/// Result of `equilibrium_wasm`, mirroring `equilibrium`'s `(MentalState, bool)`
+/// return with named fields for friendlier JS consumption.
+#[derive(Debug, Clone, Serialize)]
+pub struct EquilibriumResult {
+    pub state: MentalState,
+    pub converged: bool,
+}
+
+/// WASM entry point for the EQUILIBRIUM operator. Drives the mental-state
+/// dynamics via `evolve_mental_state` with fixed `perception_input`,
+/// `thought_input`, and `dh_dt` rates supplied from the JS side, since the
+/// trained/closure-based `PerceptionSource` drives can't cross the boundary
+/// either.
+#[wasm_bindgen]
+pub fn equilibrium_wasm(
+    initial: JsValue,
+    perception_input: f64,
+    thought_input: f64,
+    dh_dt: f64,
+    dt: f64,
+    max_time: f64,
+    tolerance: f64,
+) -> Result<JsValue, JsValue> {
+    let initial: MentalState = from_value(initial)?;
+    let (state, converged) = equilibrium(
+        &initial,
+        |s: &MentalState, step| evolve_mental_state(s, step, perception_input, thought_input, dh_dt),
+        dt,
+        max_time,
+        tolerance,
+    );
+    to_value(&EquilibriumResult { state, converged })
+}
+
+/// WASM entry point for charge variance minimization.
+#[wasm_bindgen]
+pub fn minimize_variance_wasm(rho: JsValue, max_iterations: usize, tolerance: f64, use_diis: bool) -> Result<JsValue, JsValue> {
+    let rho: Vec<f64> = from_value(rho)?;
+    let minimized = minimize_variance(&Array1::from_vec(rho), max_iterations, tolerance, use_diis);
+    to_value(&minimized.to_vec())
+}
+
+/// WASM entry point for the Boltzmann charge equilibrium distribution.
+#[wasm_bindgen]
+pub fn charge_equilibrium_wasm(rho: JsValue, temperature: f64) -> Result<JsValue, JsValue> {
+    let rho: Vec<f64> = from_value(rho)?;
+    let equilibrium_rho = charge_equilibrium(&Array1::from_vec(rho), temperature);
+    to_value(&equilibrium_rho.to_vec())
+}
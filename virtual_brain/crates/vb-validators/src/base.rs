@@ -1,10 +1,84 @@
 //! Base Validator: Abstract trait for all validators.
 
 use anyhow::Result;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+/// Severity of a validation claim, borrowed from the diagnostic model
+/// linting frameworks use: only `Error` failures sink the overall result,
+/// `Warning`/`Info` failures are recorded but non-fatal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Relative weight used by [`ValidationResult::success_rate_weighted`].
+    fn weight(self) -> f64 {
+        match self {
+            Severity::Info => 1.0,
+            Severity::Warning => 2.0,
+            Severity::Error => 3.0,
+        }
+    }
+}
+
+/// A single graded validation claim: whether it passed, how severe a
+/// failure would be, and optional diagnostic context (a message plus the
+/// expected/actual values that produced the verdict).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claim {
+    pub severity: Severity,
+    pub passed: bool,
+    pub message: Option<String>,
+    pub expected: Option<serde_json::Value>,
+    pub actual: Option<serde_json::Value>,
+}
+
+impl Claim {
+    /// A bare pass/fail claim with no diagnostic context.
+    pub fn new(severity: Severity, passed: bool) -> Self {
+        Self {
+            severity,
+            passed,
+            message: None,
+            expected: None,
+            actual: None,
+        }
+    }
+
+    /// A claim carrying a human-readable explanation of the verdict.
+    pub fn with_message(severity: Severity, passed: bool, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            passed,
+            message: Some(message.into()),
+            expected: None,
+            actual: None,
+        }
+    }
+
+    /// A claim carrying the expected/actual values behind the verdict.
+    pub fn with_values(
+        severity: Severity,
+        passed: bool,
+        expected: impl Serialize,
+        actual: impl Serialize,
+    ) -> Self {
+        Self {
+            severity,
+            passed,
+            message: None,
+            expected: Some(serde_json::to_value(expected).unwrap()),
+            actual: Some(serde_json::to_value(actual).unwrap()),
+        }
+    }
+}
+
 /// Standard validation result structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
@@ -17,37 +91,98 @@ pub struct ValidationResult {
     /// Detailed results
     pub results: HashMap<String, serde_json::Value>,
     /// Claims that were validated
-    pub claims_validated: HashMap<String, bool>,
+    pub claims_validated: HashMap<String, Claim>,
 }
 
 impl ValidationResult {
-    /// Check if all claims were validated.
+    /// Record a graded claim, replacing any prior claim of the same name.
+    pub fn add_claim(
+        &mut self,
+        claim: &str,
+        severity: Severity,
+        passed: bool,
+        message: Option<&str>,
+    ) {
+        self.claims_validated.insert(
+            claim.to_string(),
+            Claim {
+                severity,
+                passed,
+                message: message.map(str::to_string),
+                expected: None,
+                actual: None,
+            },
+        );
+    }
+
+    /// Check whether validation succeeded overall: only `Error`-severity
+    /// failures are fatal, so a run with failing warnings still succeeds.
     pub fn overall_success(&self) -> bool {
-        self.claims_validated.values().all(|&v| v)
+        self.claims_validated
+            .values()
+            .all(|c| c.passed || c.severity != Severity::Error)
     }
 
-    /// Compute validation success rate.
+    /// Compute validation success rate as a plain fraction of claims that
+    /// passed, ignoring severity.
     pub fn success_rate(&self) -> f64 {
         if self.claims_validated.is_empty() {
             return 0.0;
         }
-        let passed = self.claims_validated.values().filter(|&&v| v).count();
+        let passed = self.claims_validated.values().filter(|c| c.passed).count();
         passed as f64 / self.claims_validated.len() as f64
     }
 
+    /// Success rate weighted by claim severity, so a failing `Error` claim
+    /// costs more than a failing `Warning`/`Info` claim.
+    pub fn success_rate_weighted(&self) -> f64 {
+        let total_weight: f64 = self.claims_validated.values().map(|c| c.severity.weight()).sum();
+        if total_weight == 0.0 {
+            return 0.0;
+        }
+        let passed_weight: f64 = self
+            .claims_validated
+            .values()
+            .filter(|c| c.passed)
+            .map(|c| c.severity.weight())
+            .sum();
+        passed_weight / total_weight
+    }
+
     /// Count of validated claims.
     pub fn validated_count(&self) -> usize {
-        self.claims_validated.values().filter(|&&v| v).count()
+        self.claims_validated.values().filter(|c| c.passed).count()
     }
 
     /// Total claim count.
     pub fn total_claims(&self) -> usize {
         self.claims_validated.len()
     }
+
+    /// Count of claims at each severity, as `(total, passed)`.
+    pub fn counts_by_severity(&self, severity: Severity) -> (usize, usize) {
+        let total = self
+            .claims_validated
+            .values()
+            .filter(|c| c.severity == severity)
+            .count();
+        let passed = self
+            .claims_validated
+            .values()
+            .filter(|c| c.severity == severity && c.passed)
+            .count();
+        (total, passed)
+    }
 }
 
 /// Abstract trait for Virtual Brain validators.
 pub trait Validator: Send + Sync {
+    /// Tunable knobs for this validator (thresholds, sample counts,
+    /// integration times, ...), loadable from a JSON sidecar in
+    /// `output_dir` so tolerances and regimes can be swept without
+    /// recompiling.
+    type Params: Default + Serialize + DeserializeOwned;
+
     /// Validator name.
     fn name(&self) -> &str;
 
@@ -57,6 +192,82 @@ pub trait Validator: Send + Sync {
     /// Run validation tests.
     fn run_validation(&mut self) -> Result<ValidationResult>;
 
+    /// Apply a fully-resolved `Params` to this validator.
+    fn configure(&mut self, params: Self::Params)
+    where
+        Self: Sized;
+
+    /// Load `Params` from `<output_dir>/<name>_params.json` if present,
+    /// else `Params::default()`, and apply them via [`Validator::configure`].
+    fn configure_from_output_dir(&mut self) -> Result<()>
+    where
+        Self: Sized,
+    {
+        let path = self.output_dir().join(format!(
+            "{}_params.json",
+            self.name().to_lowercase().replace(' ', "_")
+        ));
+        let params = if path.exists() {
+            serde_json::from_str(&std::fs::read_to_string(&path)?)?
+        } else {
+            Self::Params::default()
+        };
+        self.configure(params);
+        Ok(())
+    }
+
+    /// Run `n` independent trials of `f` across the available CPUs (via
+    /// [`crate::worker::Worker`]), returning the per-trial results in
+    /// submission order. A default helper so every validator can
+    /// parallelize Monte-Carlo sweeps and batch simulations without each
+    /// reimplementing threading; reduce the results however the trial
+    /// calls for (boolean AND for bounds checks, mean for ensemble
+    /// order parameters, etc.).
+    fn run_trials<T: Send>(&self, n: usize, f: impl Fn(usize) -> T + Sync) -> Vec<T>
+    where
+        Self: Sized,
+    {
+        crate::worker::Worker::new().run_batch(n, f)
+    }
+
+    /// Run the full validation suite `repeats` times and assert the
+    /// `results` JSON is identical across all runs, recording the
+    /// outcome as a `reproducible` claim. Catches validators whose random
+    /// state isn't properly seeded, so nondeterminism shows up as a failed
+    /// claim in CI instead of an intermittent flake.
+    fn run_validation_reproducible(&mut self, repeats: usize) -> Result<ValidationResult> {
+        assert!(repeats > 0, "repeats must be at least 1");
+
+        let mut last_results: Option<serde_json::Value> = None;
+        let mut reproducible = true;
+        let mut result = None;
+
+        for _ in 0..repeats {
+            let run = self.run_validation()?;
+            // Compared as JSON values: `results` is a `HashMap`, whose key
+            // order differs between instances even for identical content.
+            let json = serde_json::to_value(&run.results)?;
+            if let Some(prev) = &last_results {
+                if prev != &json {
+                    reproducible = false;
+                }
+            }
+            last_results = Some(json);
+            result = Some(run);
+        }
+        let mut result = result.expect("repeats > 0 guarantees at least one run");
+
+        result.claims_validated.insert(
+            "reproducible".to_string(),
+            Claim::with_message(
+                Severity::Error,
+                reproducible,
+                format!("results matched across {repeats} repeats"),
+            ),
+        );
+        Ok(result)
+    }
+
     /// Save results to JSON.
     fn save_results(&self, results: &ValidationResult) -> Result<PathBuf> {
         let output_file = self.output_dir().join(format!(
@@ -72,19 +283,62 @@ pub trait Validator: Send + Sync {
         Ok(output_file)
     }
 
-    /// Print validation summary.
+    /// Render a human-readable report: a header with validator name and
+    /// timestamp, a claims table with PASS/FAIL badges and the overall
+    /// success rate, then a section per test dumping its `results` map.
+    /// Shared by every validator so the crate produces uniform, shareable
+    /// reports instead of ad-hoc `println!` output.
+    fn render_report(&self, results: &ValidationResult, fmt: ReportFormat) -> String {
+        match fmt {
+            ReportFormat::Markdown => render_markdown_report(results),
+            ReportFormat::Html => render_html_report(results),
+        }
+    }
+
+    /// Render and write a report next to the JSON results file, as
+    /// `<name>_report.md` or `<name>_report.html`.
+    fn save_report(&self, results: &ValidationResult, fmt: ReportFormat) -> Result<PathBuf> {
+        let output_file = self.output_dir().join(format!(
+            "{}_report.{}",
+            self.name().to_lowercase().replace(' ', "_"),
+            fmt.extension()
+        ));
+
+        std::fs::create_dir_all(self.output_dir())?;
+        std::fs::write(&output_file, self.render_report(results, fmt))?;
+
+        println!("[OK] Report written to: {}", output_file.display());
+        Ok(output_file)
+    }
+
+    /// Print validation summary, broken down by claim severity.
     fn print_summary(&self, results: &ValidationResult) {
         println!("\n{}", "=".repeat(70));
         println!("VALIDATION SUMMARY: {}", results.validator_name);
         println!("{}", "=".repeat(70));
 
-        for (claim, validated) in &results.claims_validated {
-            let status = if *validated {
+        for (claim, graded) in &results.claims_validated {
+            let status = if graded.passed {
                 "[OK] VALIDATED"
             } else {
-                "[FAIL] FAILED"
+                match graded.severity {
+                    Severity::Error => "[FAIL] FAILED",
+                    Severity::Warning => "[WARN] FAILED",
+                    Severity::Info => "[INFO] FAILED",
+                }
             };
-            println!("  {}: {}", claim, status);
+            print!("  {:?} {}: {}", graded.severity, claim, status);
+            match &graded.message {
+                Some(message) => println!(" ({message})"),
+                None => println!(),
+            }
+        }
+
+        for severity in [Severity::Error, Severity::Warning, Severity::Info] {
+            let (total, passed) = results.counts_by_severity(severity);
+            if total > 0 {
+                println!("  {severity:?}: {passed}/{total}");
+            }
         }
 
         let rate = results.success_rate();
@@ -100,7 +354,7 @@ pub fn create_result(
     validator_name: &str,
     parameters: HashMap<String, serde_json::Value>,
     results: HashMap<String, serde_json::Value>,
-    claims_validated: HashMap<String, bool>,
+    claims_validated: HashMap<String, Claim>,
 ) -> ValidationResult {
     ValidationResult {
         validator_name: validator_name.to_string(),
@@ -110,3 +364,207 @@ pub fn create_result(
         claims_validated,
     }
 }
+
+/// Output format for [`Validator::render_report`]/[`Validator::save_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+}
+
+fn render_markdown_report(results: &ValidationResult) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("# {}\n\n", results.validator_name));
+    out.push_str(&format!("Generated: {}\n\n", results.timestamp));
+    out.push_str(&format!(
+        "**Success rate:** {:.1}% ({}/{})\n\n",
+        results.success_rate() * 100.0,
+        results.validated_count(),
+        results.total_claims()
+    ));
+
+    out.push_str("## Claims\n\n");
+    out.push_str("| Severity | Claim | Status | Message |\n|---|---|---|---|\n");
+    let mut claim_names: Vec<&String> = results.claims_validated.keys().collect();
+    claim_names.sort();
+    for name in claim_names {
+        let claim = &results.claims_validated[name];
+        let badge = if claim.passed { "PASS" } else { "FAIL" };
+        let message = claim.message.as_deref().unwrap_or("");
+        out.push_str(&format!(
+            "| {:?} | {name} | {badge} | {message} |\n",
+            claim.severity
+        ));
+    }
+
+    out.push_str("\n## Test Results\n\n");
+    let mut result_names: Vec<&String> = results.results.keys().collect();
+    result_names.sort();
+    for name in result_names {
+        out.push_str(&format!("### {name}\n\n"));
+        out.push_str(&markdown_value_table(&results.results[name]));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn markdown_value_table(value: &serde_json::Value) -> String {
+    match value.as_object() {
+        Some(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut out = String::from("| Field | Value |\n|---|---|\n");
+            for key in keys {
+                out.push_str(&format!("| {key} | {} |\n", format_json_value(&map[key])));
+            }
+            out
+        }
+        None => format!("{}\n", format_json_value(value)),
+    }
+}
+
+/// Escapes the characters unsafe to interpolate raw into HTML text/attribute
+/// content. `render_html_report` and its table helpers interpolate
+/// validator names, claim names/messages, and JSON result values that may
+/// originate from external/untrusted data, so none of it can be trusted to
+/// be free of `<`, `>`, `&`, or quotes.
+fn html_escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn render_html_report(results: &ValidationResult) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head><meta charset=\"utf-8\"><title>");
+    out.push_str(&html_escape(&results.validator_name));
+    out.push_str("</title></head>\n<body>\n");
+    out.push_str(&format!("<h1>{}</h1>\n", html_escape(&results.validator_name)));
+    out.push_str(&format!("<p>Generated: {}</p>\n", html_escape(&results.timestamp)));
+    out.push_str(&format!(
+        "<p><strong>Success rate:</strong> {:.1}% ({}/{})</p>\n",
+        results.success_rate() * 100.0,
+        results.validated_count(),
+        results.total_claims()
+    ));
+
+    out.push_str("<h2>Claims</h2>\n<table border=\"1\">\n<tr><th>Severity</th><th>Claim</th><th>Status</th><th>Message</th></tr>\n");
+    let mut claim_names: Vec<&String> = results.claims_validated.keys().collect();
+    claim_names.sort();
+    for name in claim_names {
+        let claim = &results.claims_validated[name];
+        let (badge_class, badge) = if claim.passed {
+            ("pass", "PASS")
+        } else {
+            ("fail", "FAIL")
+        };
+        let message = html_escape(claim.message.as_deref().unwrap_or(""));
+        let name = html_escape(name);
+        out.push_str(&format!(
+            "<tr><td>{:?}</td><td>{name}</td><td class=\"{badge_class}\">{badge}</td><td>{message}</td></tr>\n",
+            claim.severity
+        ));
+    }
+    out.push_str("</table>\n");
+
+    out.push_str("<h2>Test Results</h2>\n");
+    let mut result_names: Vec<&String> = results.results.keys().collect();
+    result_names.sort();
+    for name in result_names {
+        out.push_str(&format!("<h3>{}</h3>\n", html_escape(name)));
+        out.push_str(&html_value_table(&results.results[name]));
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn html_value_table(value: &serde_json::Value) -> String {
+    match value.as_object() {
+        Some(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut out = String::from("<table border=\"1\">\n<tr><th>Field</th><th>Value</th></tr>\n");
+            for key in keys {
+                out.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td></tr>\n",
+                    html_escape(key),
+                    html_escape(&format_json_value(&map[key])),
+                ));
+            }
+            out.push_str("</table>\n");
+            out
+        }
+        None => format!("<p>{}</p>\n", html_escape(&format_json_value(value))),
+    }
+}
+
+fn format_json_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_escape_neutralizes_markup_characters() {
+        let escaped = html_escape("<script>alert('x')&\"y\"</script>");
+        assert!(!escaped.contains('<'));
+        assert!(!escaped.contains('>'));
+        assert!(escaped.contains("&lt;script&gt;"));
+        assert!(escaped.contains("&amp;"));
+        assert!(escaped.contains("&#39;"));
+        assert!(escaped.contains("&quot;"));
+    }
+
+    #[test]
+    fn test_render_html_report_escapes_untrusted_claim_and_result_data() {
+        let mut results = ValidationResult {
+            validator_name: "<b>evil</b>".to_string(),
+            timestamp: "2026-01-01".to_string(),
+            parameters: HashMap::new(),
+            results: HashMap::new(),
+            claims_validated: HashMap::new(),
+        };
+        results.add_claim(
+            "<img src=x onerror=alert(1)>",
+            Severity::Error,
+            false,
+            Some("<script>alert(1)</script>"),
+        );
+        results
+            .results
+            .insert("payload".to_string(), serde_json::json!("<script>steal()</script>"));
+
+        let html = render_html_report(&results);
+
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("<img src=x"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(html.contains("&lt;img src=x onerror=alert(1)&gt;"));
+        assert!(html.contains("&lt;b&gt;evil&lt;/b&gt;"));
+    }
+}
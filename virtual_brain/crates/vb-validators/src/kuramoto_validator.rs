@@ -1,42 +1,93 @@
 //! Kuramoto Validator: Validates Kuramoto dynamics.
 
-use crate::base::{create_result, ValidationResult, Validator};
+use crate::base::{create_result, Claim, Severity, ValidationResult, Validator};
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use vb_operators::{
-    critical_coupling, kuramoto, phase_lock, simulate_kuramoto, variance, KuramotoState,
+    critical_coupling, kuramoto, phase_lock, simulate_kuramoto, spectrum, variance, Euler, KuramotoState,
 };
 
+/// Tunable knobs for [`KuramotoValidator`], loadable from a
+/// `kuramoto_validator_params.json` sidecar via
+/// [`Validator::configure_from_output_dir`] so tolerances and regimes can be
+/// swept without recompiling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KuramotoParams {
+    /// Number of random trials for the phase-coherence bounds sweep.
+    pub n_tests: usize,
+    /// Integration time (s) for the synchronization/spectral tests.
+    pub sim_time: f64,
+    /// Integration step size.
+    pub dt: f64,
+    /// Order-parameter threshold separating synchronized from desynchronized.
+    pub sync_threshold: f64,
+    /// Multiplier on `critical_coupling` for the below-critical run.
+    pub coupling_below_factor: f64,
+    /// Multiplier on `critical_coupling` for the above-critical run.
+    pub coupling_above_factor: f64,
+    /// Maximum second-half variance still considered "converged".
+    pub convergence_tol: f64,
+}
+
+impl Default for KuramotoParams {
+    fn default() -> Self {
+        Self {
+            n_tests: 100,
+            sim_time: 10.0,
+            dt: 0.01,
+            sync_threshold: 0.5,
+            coupling_below_factor: 0.5,
+            coupling_above_factor: 2.0,
+            convergence_tol: 0.01,
+        }
+    }
+}
+
 /// Validates Kuramoto oscillator dynamics.
 pub struct KuramotoValidator {
     n_oscillators: usize,
     output_dir: PathBuf,
+    /// Seed for every random `KuramotoState` this validator constructs, so a
+    /// run is byte-for-byte replayable from `ValidationResult::parameters`.
+    seed: u64,
+    params: KuramotoParams,
 }
 
 impl KuramotoValidator {
-    /// Create new Kuramoto validator.
+    /// Create new Kuramoto validator, seeded with a fixed default seed and
+    /// default params.
     pub fn new(n_oscillators: usize, output_dir: impl AsRef<Path>) -> Self {
+        Self::with_seed(n_oscillators, output_dir, 42)
+    }
+
+    /// Create a new Kuramoto validator with an explicit seed, for replaying
+    /// a specific (possibly failing) run.
+    pub fn with_seed(n_oscillators: usize, output_dir: impl AsRef<Path>, seed: u64) -> Self {
         Self {
             n_oscillators,
             output_dir: output_dir.as_ref().to_path_buf(),
+            seed,
+            params: KuramotoParams::default(),
         }
     }
 
     fn test_phase_coherence_bounds(&self) -> (HashMap<String, serde_json::Value>, bool) {
-        let mut all_valid = true;
-        let n_tests = 100;
+        let n_tests = self.params.n_tests;
+        let n_oscillators = self.n_oscillators;
+        let seed = self.seed;
 
-        for _ in 0..n_tests {
-            let state = KuramotoState::random(self.n_oscillators, 10.0, 1.0, 0.5);
+        // Embarrassingly parallel: each trial is an independent random
+        // Kuramoto state, reduced with a boolean AND over all of them.
+        let in_bounds: Vec<bool> = self.run_trials(n_tests, move |i| {
+            let state =
+                KuramotoState::random_seeded(n_oscillators, 10.0, 1.0, 0.5, seed.wrapping_add(i as u64));
             let (r, _) = phase_lock(&state.phases);
-
-            if r < 0.0 || r > 1.0 {
-                all_valid = false;
-                break;
-            }
-        }
+            (0.0..=1.0).contains(&r)
+        });
+        let all_valid = in_bounds.iter().all(|&v| v);
 
         let mut results = HashMap::new();
         results.insert("n_tests".to_string(), json!(n_tests));
@@ -47,19 +98,34 @@ impl KuramotoValidator {
     fn test_synchronization_onset(&self) -> (HashMap<String, serde_json::Value>, bool) {
         let frequency_std = 1.0;
         let k_c = critical_coupling(frequency_std, self.n_oscillators);
+        let n_oscillators = self.n_oscillators;
+        let seed = self.seed;
+        let sim_time = self.params.sim_time;
+        let dt = self.params.dt;
+        let sync_threshold = self.params.sync_threshold;
 
-        // Below critical coupling
-        let state_below = KuramotoState::random(self.n_oscillators, 10.0, frequency_std, k_c * 0.5);
-        let (_, r_below, _) = simulate_kuramoto(&state_below, 10.0, 0.01);
-        let final_r_below = r_below[r_below.len() - 1];
-
-        // Above critical coupling
-        let state_above = KuramotoState::random(self.n_oscillators, 10.0, frequency_std, k_c * 2.0);
-        let (_, r_above, _) = simulate_kuramoto(&state_above, 10.0, 0.01);
-        let final_r_above = r_above[r_above.len() - 1];
+        // Below/above critical coupling are independent full integrations;
+        // run them concurrently instead of back-to-back.
+        let couplings = [
+            k_c * self.params.coupling_below_factor,
+            k_c * self.params.coupling_above_factor,
+        ];
+        let final_r: Vec<f64> = self.run_trials(couplings.len(), move |i| {
+            let state = KuramotoState::random_seeded(
+                n_oscillators,
+                10.0,
+                frequency_std,
+                couplings[i],
+                seed.wrapping_add(1_000 + i as u64),
+            );
+            let (_, r, _) = simulate_kuramoto(&state, sim_time, dt, &Euler);
+            r[r.len() - 1]
+        });
+        let final_r_below = final_r[0];
+        let final_r_above = final_r[1];
 
-        let sync_below = final_r_below < 0.5;
-        let sync_above = final_r_above > 0.5;
+        let sync_below = final_r_below < sync_threshold;
+        let sync_above = final_r_above > sync_threshold;
 
         let mut results = HashMap::new();
         results.insert("critical_coupling".to_string(), json!(k_c));
@@ -71,9 +137,63 @@ impl KuramotoValidator {
         (results, sync_below && sync_above)
     }
 
+    /// FFT-based alternative to the bare `r > 0.5` synchronization test:
+    /// the synchronized (above-critical) regime should collapse onto a
+    /// single dominant collective oscillation of `r(t)` (low spectral
+    /// entropy), while the incoherent (below-critical) regime stays
+    /// broadband (high spectral entropy) even if its mean `r` briefly
+    /// crosses 0.5.
+    fn test_spectral_synchronization(&self) -> (HashMap<String, serde_json::Value>, bool) {
+        let frequency_std = 1.0;
+        let k_c = critical_coupling(frequency_std, self.n_oscillators);
+        let dt = self.params.dt;
+        let sim_time = self.params.sim_time;
+
+        let state_below = KuramotoState::random_seeded(
+            self.n_oscillators,
+            10.0,
+            frequency_std,
+            k_c * self.params.coupling_below_factor,
+            self.seed.wrapping_add(2_000),
+        );
+        let (_, r_below, _) = simulate_kuramoto(&state_below, sim_time, dt, &Euler);
+        let below = spectrum(&r_below, dt, 3);
+
+        let state_above = KuramotoState::random_seeded(
+            self.n_oscillators,
+            10.0,
+            frequency_std,
+            k_c * self.params.coupling_above_factor,
+            self.seed.wrapping_add(2_001),
+        );
+        let (_, r_above, _) = simulate_kuramoto(&state_above, sim_time, dt, &Euler);
+        let above = spectrum(&r_above, dt, 3);
+
+        let discriminates =
+            above.normalized_entropy < 0.5 && above.normalized_entropy < below.normalized_entropy;
+
+        let mut results = HashMap::new();
+        results.insert("below_entropy".to_string(), json!(below.normalized_entropy));
+        results.insert("above_entropy".to_string(), json!(above.normalized_entropy));
+        results.insert(
+            "above_peak_frequency".to_string(),
+            json!(above.dominant_frequencies.first().copied().unwrap_or(0.0)),
+        );
+        results.insert("discriminates_regimes".to_string(), json!(discriminates));
+
+        (results, discriminates)
+    }
+
     fn test_order_parameter_convergence(&self) -> (HashMap<String, serde_json::Value>, bool) {
-        let state = KuramotoState::random(self.n_oscillators, 10.0, 0.5, 2.0);
-        let (_, r_values, _) = simulate_kuramoto(&state, 20.0, 0.01);
+        let state = KuramotoState::random_seeded(
+            self.n_oscillators,
+            10.0,
+            0.5,
+            2.0,
+            self.seed.wrapping_add(3_000),
+        );
+        let (_, r_values, _) =
+            simulate_kuramoto(&state, self.params.sim_time * 2.0, self.params.dt, &Euler);
 
         // Check if variance decreases over time
         let n = r_values.len();
@@ -83,7 +203,7 @@ impl KuramotoValidator {
         let var_first = variance(&first_half);
         let var_second = variance(&second_half);
 
-        let converges = var_second < var_first || var_second < 0.01;
+        let converges = var_second < var_first || var_second < self.params.convergence_tol;
 
         let mut results = HashMap::new();
         results.insert("variance_first_half".to_string(), json!(var_first));
@@ -95,6 +215,8 @@ impl KuramotoValidator {
 }
 
 impl Validator for KuramotoValidator {
+    type Params = KuramotoParams;
+
     fn name(&self) -> &str {
         "Kuramoto Validator"
     }
@@ -103,6 +225,10 @@ impl Validator for KuramotoValidator {
         &self.output_dir
     }
 
+    fn configure(&mut self, params: KuramotoParams) {
+        self.params = params;
+    }
+
     fn run_validation(&mut self) -> Result<ValidationResult> {
         println!("{}", "=".repeat(70));
         println!("KURAMOTO DYNAMICS VALIDATION");
@@ -120,18 +246,38 @@ impl Validator for KuramotoValidator {
         let (conv_results, conv_valid) = self.test_order_parameter_convergence();
         println!("   Result: {}", if conv_valid { "PASS" } else { "FAIL" });
 
+        println!("4. Testing spectral discrimination of synchronization regimes...");
+        let (spectral_results, spectral_valid) = self.test_spectral_synchronization();
+        println!("   Result: {}", if spectral_valid { "PASS" } else { "FAIL" });
+
         let mut claims = HashMap::new();
-        claims.insert("phase_coherence_bounded".to_string(), bounds_valid);
-        claims.insert("sync_above_critical".to_string(), sync_valid);
-        claims.insert("order_param_converges".to_string(), conv_valid);
+        claims.insert(
+            "phase_coherence_bounded".to_string(),
+            Claim::new(Severity::Error, bounds_valid),
+        );
+        claims.insert(
+            "sync_above_critical".to_string(),
+            Claim::new(Severity::Error, sync_valid),
+        );
+        claims.insert(
+            "order_param_converges".to_string(),
+            Claim::new(Severity::Error, conv_valid),
+        );
+        claims.insert(
+            "spectral_entropy_discriminates_regimes".to_string(),
+            Claim::new(Severity::Warning, spectral_valid),
+        );
 
         let mut all_results = HashMap::new();
         all_results.insert("bounds".to_string(), json!(bounds_results));
         all_results.insert("synchronization".to_string(), json!(sync_results));
         all_results.insert("convergence".to_string(), json!(conv_results));
+        all_results.insert("spectral".to_string(), json!(spectral_results));
 
         let mut params = HashMap::new();
         params.insert("n_oscillators".to_string(), json!(self.n_oscillators));
+        params.insert("seed".to_string(), json!(self.seed));
+        params.insert("config".to_string(), json!(self.params));
 
         let result = create_result(self.name(), params, all_results, claims);
         self.save_results(&result)?;
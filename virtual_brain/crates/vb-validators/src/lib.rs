@@ -9,6 +9,7 @@
 //! - `PartitionValidator`: Validates partition coordinate system
 //! - `KuramotoValidator`: Validates Kuramoto dynamics
 //! - `ConsciousnessValidator`: Validates consciousness equations
+//! - `SpectralValidator`: Validates measured vs. closed-form oscillation frequency
 //! - `ValidationSuite`: Orchestrates all validators
 
 pub mod base;
@@ -16,9 +17,13 @@ pub mod consciousness_validator;
 pub mod kuramoto_validator;
 pub mod orchestrator;
 pub mod partition_validator;
+pub mod spectral_validator;
+pub mod worker;
 
-pub use base::{ValidationResult, Validator};
+pub use base::{Claim, ReportFormat, Severity, ValidationResult, Validator};
 pub use consciousness_validator::ConsciousnessValidator;
-pub use kuramoto_validator::KuramotoValidator;
+pub use kuramoto_validator::{KuramotoParams, KuramotoValidator};
 pub use orchestrator::ValidationSuite;
 pub use partition_validator::PartitionValidator;
+pub use spectral_validator::SpectralValidator;
+pub use worker::Worker;
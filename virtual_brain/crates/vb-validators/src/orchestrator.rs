@@ -4,6 +4,7 @@ use crate::base::{ValidationResult, Validator};
 use crate::consciousness_validator::ConsciousnessValidator;
 use crate::kuramoto_validator::KuramotoValidator;
 use crate::partition_validator::PartitionValidator;
+use crate::spectral_validator::SpectralValidator;
 use anyhow::Result;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -51,21 +52,43 @@ impl ValidationSuite {
                 "consciousness",
                 Box::new(ConsciousnessValidator::new(self.output_dir.join("consciousness"))),
             ),
+            (
+                "spectral",
+                Box::new(SpectralValidator::new(self.output_dir.join("spectral"))),
+            ),
         ];
 
         let total = validators.len();
 
-        for (idx, (name, mut validator)) in validators.into_iter().enumerate() {
-            if skip_validators.contains(&name) {
-                println!("\n[SKIP] Validator {}/{}: {}", idx + 1, total, name);
-                continue;
-            }
+        // Independent validators have no shared state, so run them
+        // concurrently (one thread per validator) rather than serially.
+        let runnable: Vec<(&str, Box<dyn Validator>)> = validators
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, (name, validator))| {
+                if skip_validators.contains(&name) {
+                    println!("\n[SKIP] Validator {}/{}: {}", idx + 1, total, name);
+                    None
+                } else {
+                    Some((name, validator))
+                }
+            })
+            .collect();
+
+        let outcomes: Vec<(&str, Result<ValidationResult>)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = runnable
+                .into_iter()
+                .map(|(name, mut validator)| scope.spawn(move || (name, validator.run_validation())))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
 
+        for (name, outcome) in outcomes {
             println!("\n{}", "=".repeat(70));
-            println!("VALIDATOR {}/{}: {}", idx + 1, total, name.to_uppercase());
+            println!("VALIDATOR: {}", name.to_uppercase());
             println!("{}", "=".repeat(70));
 
-            match validator.run_validation() {
+            match outcome {
                 Ok(result) => {
                     self.results.insert(name.to_string(), result);
                 }
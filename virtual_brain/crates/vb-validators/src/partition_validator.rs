@@ -1,12 +1,12 @@
 //! Partition Validator: Validates partition coordinate system.
 
-use crate::base::{create_result, ValidationResult, Validator};
+use crate::base::{create_result, Claim, Severity, ValidationResult, Validator};
 use anyhow::Result;
 use serde_json::json;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use vb_core::types::PartitionCoord;
-use vb_operators::partition_ops::{capacity, d_cat};
+use vb_core::types::{PartitionCoord, Spin};
+use vb_operators::partition_ops::{capacity, d_cat, quiet_softmax};
 
 /// Validates partition coordinate system properties.
 pub struct PartitionValidator {
@@ -118,9 +118,29 @@ impl PartitionValidator {
         results.insert("symmetric".to_string(), json!(symmetric));
         (results, symmetric)
     }
+
+    fn test_quiet_softmax_distribution(&self) -> (HashMap<String, serde_json::Value>, bool) {
+        let reference = PartitionCoord::new(2, 1, 0, Spin::Up).unwrap_or_default();
+        let states: Vec<_> = PartitionCoord::iter_all(self.n_max.min(4)).collect();
+
+        let weights = quiet_softmax(&reference, &states, 1.0);
+        let total: f64 = weights.iter().map(|(_, w)| *w).sum();
+
+        let all_bounded = weights.iter().all(|(_, w)| *w >= 0.0 && *w <= 1.0);
+        let sums_within_bound = total <= 1.0 + 1e-9;
+        let valid = all_bounded && sums_within_bound;
+
+        let mut results = HashMap::new();
+        results.insert("total_weight".to_string(), json!(total));
+        results.insert("all_weights_bounded".to_string(), json!(all_bounded));
+        results.insert("sums_within_bound".to_string(), json!(sums_within_bound));
+        (results, valid)
+    }
 }
 
 impl Validator for PartitionValidator {
+    type Params = ();
+
     fn name(&self) -> &str {
         "Partition Validator"
     }
@@ -129,6 +149,8 @@ impl Validator for PartitionValidator {
         &self.output_dir
     }
 
+    fn configure(&mut self, _params: ()) {}
+
     fn run_validation(&mut self) -> Result<ValidationResult> {
         println!("{}", "=".repeat(70));
         println!("PARTITION COORDINATE VALIDATION");
@@ -150,17 +172,38 @@ impl Validator for PartitionValidator {
         let (symmetry_results, symmetry_valid) = self.test_distance_symmetry();
         println!("   Result: {}", if symmetry_valid { "PASS" } else { "FAIL" });
 
+        println!("5. Testing quiet softmax distribution bounds...");
+        let (softmax_results, softmax_valid) = self.test_quiet_softmax_distribution();
+        println!("   Result: {}", if softmax_valid { "PASS" } else { "FAIL" });
+
         let mut claims = HashMap::new();
-        claims.insert("capacity_formula_correct".to_string(), capacity_valid);
-        claims.insert("total_capacity_correct".to_string(), total_valid);
-        claims.insert("linear_index_bijective".to_string(), bijection_valid);
-        claims.insert("distance_symmetric".to_string(), symmetry_valid);
+        claims.insert(
+            "capacity_formula_correct".to_string(),
+            Claim::new(Severity::Error, capacity_valid),
+        );
+        claims.insert(
+            "total_capacity_correct".to_string(),
+            Claim::new(Severity::Error, total_valid),
+        );
+        claims.insert(
+            "linear_index_bijective".to_string(),
+            Claim::new(Severity::Error, bijection_valid),
+        );
+        claims.insert(
+            "distance_symmetric".to_string(),
+            Claim::new(Severity::Error, symmetry_valid),
+        );
+        claims.insert(
+            "quiet_softmax_valid".to_string(),
+            Claim::new(Severity::Error, softmax_valid),
+        );
 
         let mut all_results = HashMap::new();
         all_results.insert("capacity".to_string(), json!(capacity_results));
         all_results.insert("total_capacity".to_string(), json!(total_results));
         all_results.insert("bijection".to_string(), json!(bijection_results));
         all_results.insert("symmetry".to_string(), json!(symmetry_results));
+        all_results.insert("quiet_softmax".to_string(), json!(softmax_results));
 
         let mut params = HashMap::new();
         params.insert("n_max".to_string(), json!(self.n_max));
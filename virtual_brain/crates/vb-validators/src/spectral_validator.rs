@@ -0,0 +1,93 @@
+//! Spectral Validator: Validates measured vs. closed-form consciousness frequency.
+
+use crate::base::{create_result, Claim, Severity, ValidationResult, Validator};
+use anyhow::Result;
+use serde_json::json;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::path::{Path, PathBuf};
+use vb_core::types::MentalState;
+use vb_operators::neural_ops::{consciousness_frequency, consciousness_time_series};
+use vb_operators::spectral::dominant_frequency;
+
+/// Validates that the FFT-measured dominant frequency of a simulated
+/// consciousness run matches the `consciousness_frequency` closed form.
+pub struct SpectralValidator {
+    output_dir: PathBuf,
+}
+
+impl SpectralValidator {
+    /// Create new spectral validator.
+    pub fn new(output_dir: impl AsRef<Path>) -> Self {
+        Self {
+            output_dir: output_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn test_frequency_matches_closed_form(&self) -> (HashMap<String, serde_json::Value>, Claim) {
+        let omega_thought = 2.0 * PI * 10.0;
+        let omega_perception = 2.0 * PI * 6.0;
+        let drive_frequency = consciousness_frequency(omega_thought, omega_perception) / (2.0 * PI);
+
+        let dt = 1.0 / (drive_frequency * 32.0).max(64.0);
+        let duration = 4.0;
+
+        let perception_profile =
+            |t: f64| 0.5 + 0.5 * (2.0 * PI * drive_frequency * t).sin();
+
+        let initial = MentalState::default();
+        let (_times, c_series) =
+            consciousness_time_series(&initial, duration, dt, &perception_profile);
+
+        let measured = dominant_frequency(&c_series, dt, true);
+        let tolerance = drive_frequency * 0.25 + 0.1;
+        let matches = (measured - drive_frequency).abs() < tolerance;
+
+        let mut results = HashMap::new();
+        results.insert("expected_frequency".to_string(), json!(drive_frequency));
+        results.insert("measured_frequency".to_string(), json!(measured));
+        results.insert("tolerance".to_string(), json!(tolerance));
+        results.insert("frequency_matches".to_string(), json!(matches));
+
+        let claim = Claim::with_values(Severity::Error, matches, drive_frequency, measured);
+        (results, claim)
+    }
+}
+
+impl Validator for SpectralValidator {
+    type Params = ();
+
+    fn name(&self) -> &str {
+        "Spectral Validator"
+    }
+
+    fn configure(&mut self, _params: ()) {}
+
+    fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    fn run_validation(&mut self) -> Result<ValidationResult> {
+        println!("{}", "=".repeat(70));
+        println!("SPECTRAL ANALYSIS VALIDATION");
+        println!("{}", "=".repeat(70));
+
+        println!("\n1. Testing measured dominant frequency against closed form...");
+        let (freq_results, freq_claim) = self.test_frequency_matches_closed_form();
+        println!("   Result: {}", if freq_claim.passed { "PASS" } else { "FAIL" });
+
+        let mut claims = HashMap::new();
+        claims.insert("frequency_matches".to_string(), freq_claim);
+
+        let mut all_results = HashMap::new();
+        all_results.insert("frequency".to_string(), json!(freq_results));
+
+        let params = HashMap::new();
+
+        let result = create_result(self.name(), params, all_results, claims);
+        self.save_results(&result)?;
+        self.print_summary(&result);
+
+        Ok(result)
+    }
+}
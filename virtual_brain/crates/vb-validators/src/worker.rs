@@ -0,0 +1,109 @@
+//! Worker: bellman-style chunked multicore execution abstraction.
+//!
+//! Splits an element range (or a batch of independent jobs) into
+//! `ceil(len / cpus)`-sized contiguous chunks, runs one thread per
+//! chunk via `std::thread::scope`, and collects the partial results.
+//! Per-chunk RNG seeding (base seed + chunk index) keeps Monte-Carlo
+//! results reproducible for a given CPU count; since the chunking follows
+//! the CPU count, a different count draws different samples.
+
+use std::ops::Range;
+use std::thread;
+
+/// Chunked thread-pool executor.
+pub struct Worker {
+    cpus: usize,
+}
+
+impl Worker {
+    /// Create a new worker, detecting the available CPU count.
+    pub fn new() -> Self {
+        let cpus = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        Self { cpus }
+    }
+
+    /// Number of CPUs this worker will split work across.
+    pub fn cpus(&self) -> usize {
+        self.cpus
+    }
+
+    /// Split `[0, len)` into contiguous chunks (one per CPU) and run `f`
+    /// on each chunk concurrently, returning the per-chunk results in
+    /// order. `f` receives the chunk's index range and its chunk index
+    /// (for deterministic RNG seeding).
+    pub fn scope<T, F>(&self, len: usize, f: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(Range<usize>, usize) -> T + Sync,
+    {
+        if len == 0 {
+            return Vec::new();
+        }
+
+        let chunk_size = (len + self.cpus - 1) / self.cpus;
+        let mut ranges = Vec::new();
+        let mut start = 0;
+        let mut chunk_idx = 0;
+        while start < len {
+            let end = (start + chunk_size).min(len);
+            ranges.push((start..end, chunk_idx));
+            start = end;
+            chunk_idx += 1;
+        }
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = ranges
+                .into_iter()
+                .map(|(range, idx)| scope.spawn(|| f(range, idx)))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        })
+    }
+
+    /// Run a batch of independent thunks concurrently, one per chunk,
+    /// returning all results flattened back into submission order.
+    pub fn run_batch<T, F>(&self, n: usize, f: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(usize) -> T + Sync,
+    {
+        let chunks = self.scope(n, |range, _chunk_idx| {
+            range.map(|i| f(i)).collect::<Vec<T>>()
+        });
+        chunks.into_iter().flatten().collect()
+    }
+}
+
+impl Default for Worker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_covers_all_indices() {
+        let worker = Worker::new();
+        let chunks = worker.scope(37, |range, _idx| range.collect::<Vec<usize>>());
+        let mut all: Vec<usize> = chunks.into_iter().flatten().collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..37).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_run_batch_preserves_order() {
+        let worker = Worker::new();
+        let results = worker.run_batch(50, |i| i * 2);
+        assert_eq!(results, (0..50).map(|i| i * 2).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_empty_scope() {
+        let worker = Worker::new();
+        let chunks: Vec<Vec<usize>> = worker.scope(0, |range, _idx| range.collect());
+        assert!(chunks.is_empty());
+    }
+}